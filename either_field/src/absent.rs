@@ -0,0 +1,68 @@
+/// Marks a field as missing from a derived struct
+///
+/// Unlike `()`, which a field could genuinely have, `Absent` is only ever used
+/// to leave a field out, so `OmitAbsentFields` can delete these fields without
+/// touching the `()` ones:
+/// ```
+/// # use either_field::{Absent, either, make_template};
+/// # struct PlayerData;
+/// #[make_template(
+///     GenStructs: true,
+///     OmitAbsentFields: true;
+///     Anonymous: [player: Absent]
+/// )]
+/// struct Score {
+///     player: either!(PlayerData | Absent),
+///     value: i32
+/// }
+/// ```
+///
+/// With the `serde` feature, it serializes as nothing (`None`), and deserializes
+/// from either nothing or a missing field. Fields of templates deriving `Serialize`
+/// are skipped entirely when they're `Absent`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Absent;
+
+impl Absent {
+    /// Whether the value is of type `Absent`, used to skip serializing
+    /// the fields of templates which can be `Absent`.
+    ///
+    /// This is best-effort: `TypeId` would require `T: 'static`, which fields with
+    /// lifetimes aren't, so the names from `core::any::type_name` are compared instead,
+    /// which the standard library doesn't guarantee to be unique.
+    #[doc(hidden)]
+    pub fn is_absent<T: ?Sized>(_: &T) -> bool {
+        core::any::type_name::<T>() == core::any::type_name::<Absent>()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Absent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_none()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Absent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AbsentVisitor;
+        impl<'de> serde::de::Visitor<'de> for AbsentVisitor {
+            type Value = Absent;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("nothing")
+            }
+
+            fn visit_none<E: serde::de::Error>(self) -> Result<Absent, E> {
+                Ok(Absent)
+            }
+
+            fn visit_unit<E: serde::de::Error>(self) -> Result<Absent, E> {
+                Ok(Absent)
+            }
+        }
+        // missing fields are deserialized as options
+        deserializer.deserialize_option(AbsentVisitor)
+    }
+}
@@ -0,0 +1,54 @@
+/// Implemented by every template, listing the types of its derived structs
+///
+/// Without `GenStructs`, the template is the instance of it with every field
+/// using its default type.
+pub trait Template {
+    /// A tuple with a `PhantomData` of every derived struct,
+    /// so that unsized derived structs can be listed too
+    type Variants;
+}
+
+/// Implemented by every derived struct of the template `T`
+///
+/// ```
+/// # use either_field::{HasField, VariantOf, either, field_id, make_template};
+/// # #[make_template(
+/// #     GenStructs: true,
+/// #     GenTraits: true;
+/// #     ScoreWithId: [player: u64],
+/// #     ScoreWithName: [player: String]
+/// # )]
+/// # struct Score {
+/// #     player: either!(u64 | String),
+/// #     value: i32,
+/// # }
+/// fn total<S: VariantOf<Score> + HasField<{ field_id("value") }, Type = i32>>(scores: &[S]) -> i32 {
+///     scores.iter().map(|score| *score.field()).sum()
+/// }
+/// ```
+pub trait VariantOf<T: Template + ?Sized> {}
+
+/// Implemented for every field the struct has, `ID` being [`field_id`] of the field's name
+/// (or of its position, for tuple structs)
+pub trait HasField<const ID: u64> {
+    /// The type of the field
+    type Type: ?Sized;
+
+    fn field(&self) -> &Self::Type;
+    fn field_mut(&mut self) -> &mut Self::Type;
+}
+
+/// Turns a field's name into what [`HasField`] is implemented with
+///
+/// Const generics can't be strings yet, so the name is hashed (with FNV-1a)
+pub const fn field_id(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash = 0xcbf29ce484222325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+    hash
+}
@@ -0,0 +1,159 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Generics, Ident, Type, Visibility};
+
+use crate::{
+    helper,
+    minor_parsing::Settings,
+    template::{TemplateField, Variant},
+    transitions::{access, generic_args},
+};
+
+// The trait of the field, and its associated type
+//
+// `ScoreHasPlayer` and `Player` for `player` in `Score`, `WrapperHasField0` and `Field0` for `0`,
+// so that two templates in the same module don't generate the same trait
+fn names(template: &Ident, field: &TemplateField) -> (Ident, Ident) {
+    let base = match &field.field.ident {
+        Some(_) => helper::to_camel_case(&field.key),
+        None => format!("Field{}", field.key),
+    };
+    (
+        format_ident!("{}Has{}", template, base),
+        format_ident!("{}", base),
+    )
+}
+
+fn methods(field: &TemplateField) -> (Ident, Ident, Ident) {
+    (
+        format_ident!("get_{}", field.key),
+        format_ident!("get_{}_mut", field.key),
+        format_ident!("into_{}", field.key),
+    )
+}
+
+// Every `either!` field gets a trait, implemented
+// by whatever has the field no matter its type
+pub(crate) fn gen_accessor_traits(
+    vis: &Visibility,
+    template: &Ident,
+    fields: &[TemplateField],
+) -> TokenStream {
+    let traits = fields
+        .iter()
+        .filter(|field| field.either.is_some())
+        .map(|field| {
+            let (trait_name, assoc) = names(template, field);
+            let (get, get_mut, into) = methods(field);
+            let key = &field.key;
+            let docs = [
+                format!("Implemented by everything with the field `{key}` of `{template}`"),
+                format!("The type of `{key}`"),
+                format!("Borrows `{key}`"),
+                format!("Mutably borrows `{key}`"),
+                format!("Turns it into `{key}`"),
+            ];
+            let [trait_doc, assoc_doc, get_doc, get_mut_doc, into_doc] = &docs;
+            quote! {
+                #[doc = #trait_doc]
+                #vis trait #trait_name {
+                    #[doc = #assoc_doc]
+                    type #assoc;
+                    #[doc = #get_doc]
+                    fn #get(&self) -> &Self::#assoc;
+                    #[doc = #get_mut_doc]
+                    fn #get_mut(&mut self) -> &mut Self::#assoc;
+                    #[doc = #into_doc]
+                    fn #into(self) -> Self::#assoc;
+                }
+            }
+        });
+    quote! { #(#traits)* }
+}
+
+fn accessor_impl(
+    template: &Ident,
+    field: &TemplateField,
+    ty: &Type,
+    position: usize,
+    name: &TokenStream,
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (trait_name, assoc) = names(template, field);
+    let (get, get_mut, into) = methods(field);
+    let accessed = access(field, position);
+    quote! {
+        impl #impl_generics #trait_name for #name #where_clause {
+            type #assoc = #ty;
+            fn #get(&self) -> &Self::#assoc {
+                &#accessed
+            }
+            fn #get_mut(&mut self) -> &mut Self::#assoc {
+                &mut #accessed
+            }
+            fn #into(self) -> Self::#assoc {
+                #accessed
+            }
+        }
+    }
+}
+
+// In type declaration mode, every instance of the template has every field
+//
+// `template` is the template after its fields have been turned into generics
+pub(crate) fn gen_generic_accessors(
+    template: &syn::ItemStruct,
+    fields: &[TemplateField],
+) -> TokenStream {
+    let name = &template.ident;
+    let args = generic_args(&template.generics, None);
+    let mut out = gen_accessor_traits(&template.vis, name, fields);
+    for (field_number, (field, template_field)) in template.fields.iter().zip(fields).enumerate() {
+        if template_field.either.is_some() {
+            out.extend(accessor_impl(
+                name,
+                template_field,
+                &field.ty,
+                field_number,
+                &quote! { #name #args },
+                &template.generics,
+            ));
+        }
+    }
+    out
+}
+
+// With generated structs, the fields which are omitted don't get the trait
+pub(crate) fn gen_struct_accessors(
+    vis: &Visibility,
+    template: &Ident,
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+) -> TokenStream {
+    let args = generic_args(generics, None);
+    let mut out = gen_accessor_traits(vis, template, fields);
+    for variant in variants {
+        let variant_name = &variant.derived.name;
+        let mut position = 0;
+        for (field_number, field) in fields.iter().enumerate() {
+            if variant.is_omitted(field_number, settings) {
+                continue;
+            }
+            if field.either.is_some() {
+                out.extend(accessor_impl(
+                    template,
+                    field,
+                    &variant.types[field_number],
+                    position,
+                    &quote! { #variant_name #args },
+                    generics,
+                ));
+            }
+            position += 1;
+        }
+    }
+    out
+}
@@ -0,0 +1,275 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Generics, Type};
+
+use crate::{
+    minor_parsing::Settings,
+    template::{self, TemplateField, Variant},
+    transitions::generic_args,
+};
+
+// Fails to compile if `ty` doesn't satisfy `bound`, with an error
+// pointing at a function named after the derived struct and the field
+pub(crate) fn assert_bound(
+    variant: &Variant,
+    field: &TemplateField,
+    ty: &Type,
+    bound: &TokenStream,
+    requirement: &str,
+    generics: &Generics,
+) -> TokenStream {
+    let assertion = format_ident!(
+        "{}_field_{}_{}",
+        variant.derived.name,
+        field.key,
+        requirement
+    );
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    quote! {
+        const _: () = {
+            #[allow(non_snake_case)]
+            fn #assertion<T: ?::core::marker::Sized + #bound>() {}
+            #[allow(dead_code)]
+            fn check #impl_generics () #where_clause {
+                #assertion::<#ty>();
+            }
+        };
+    }
+}
+
+// Union members have to either be `Copy` or `ManuallyDrop`
+pub(crate) fn gen_union_assertions(
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+) -> TokenStream {
+    let mut out = TokenStream::new();
+    for variant in variants {
+        for (field_number, (field, ty)) in fields.iter().zip(&variant.types).enumerate() {
+            if field.either.is_none()
+                || variant.is_omitted(field_number, settings)
+                || template::is_manually_drop(ty)
+            {
+                continue;
+            }
+            out.extend(assert_bound(
+                variant,
+                field,
+                ty,
+                &quote! { ::core::marker::Copy },
+                "must_be_Copy_or_ManuallyDrop",
+                generics,
+            ));
+        }
+    }
+    out
+}
+
+// The types picked for a bound (`impl Trait`) have to satisfy it.
+//
+// Type declarations aren't checked until they're used, so this
+// is needed even when the generic parameter has the bound
+pub(crate) fn gen_bound_assertions(
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+) -> TokenStream {
+    let mut out = TokenStream::new();
+    for variant in variants {
+        for (field, ty) in fields.iter().zip(&variant.types) {
+            let Some(either) = &field.either else {
+                continue;
+            };
+            let Some(bound) = either.bound() else {
+                continue;
+            };
+            if either.types.contains(ty) {
+                continue;
+            }
+            let bounds = &bound.bounds;
+            out.extend(assert_bound(
+                variant,
+                field,
+                ty,
+                &quote! { #bounds },
+                "must_satisfy_bound",
+                generics,
+            ));
+        }
+    }
+    out
+}
+
+// What an assertion of the bound is named after, i.e. `must_be_Send`
+fn requirement(bound: &syn::TypeParamBound) -> String {
+    match bound {
+        syn::TypeParamBound::Trait(x) => match x.path.segments.last() {
+            Some(segment) => format!("must_be_{}", segment.ident),
+            None => String::from("must_satisfy_bound"),
+        },
+        syn::TypeParamBound::Lifetime(x) => format!("must_outlive_{}", x.ident),
+        _ => String::from("must_satisfy_bound"),
+    }
+}
+
+// With AssertImpl, every field of every derived struct has to satisfy the bounds,
+// and so does the derived struct itself (for traits which aren't derived from the fields)
+pub(crate) fn gen_impl_assertions(
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+) -> TokenStream {
+    let mut out = TokenStream::new();
+    let args = generic_args(generics, None);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    for variant in variants {
+        for bound in &settings.assert_impl {
+            let requirement = requirement(bound);
+            for (field_number, (field, ty)) in fields.iter().zip(&variant.types).enumerate() {
+                if variant.is_omitted(field_number, settings) {
+                    continue;
+                }
+                out.extend(assert_bound(
+                    variant,
+                    field,
+                    ty,
+                    &quote! { #bound },
+                    &requirement,
+                    generics,
+                ));
+            }
+
+            let name = &variant.derived.name;
+            let assertion = format_ident!("{}_{}", name, requirement);
+            out.extend(quote! {
+                const _: () = {
+                    #[allow(non_snake_case)]
+                    fn #assertion<T: ?::core::marker::Sized + #bound>() {}
+                    #[allow(dead_code)]
+                    fn check #impl_generics () #where_clause {
+                        #assertion::<#name #args>();
+                    }
+                };
+            });
+        }
+    }
+    out
+}
+
+// Whether the attributes include `#[repr(representation)]`, possibly
+// next to other representations, i.e. `C` in `#[repr(C, packed)]`
+pub(crate) fn has_repr(attrs: &[syn::Attribute], representation: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("repr")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+                )
+                .is_ok_and(|list| list.iter().any(|meta| meta.path().is_ident(representation)))
+    })
+}
+
+pub(crate) fn is_repr_c(attrs: &[syn::Attribute]) -> bool {
+    has_repr(attrs, "C")
+}
+
+// Checks that every derived struct places the fields which aren't
+// using `either!` at the same offsets as the first derived struct does,
+// and has the same size whenever the `either!` fields have the same sizes
+pub(crate) fn gen_layout_assertions(
+    attrs: &[syn::Attribute],
+    generics: &Generics,
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    settings: &Settings,
+) -> Result<TokenStream, String> {
+    if !is_repr_c(attrs) {
+        return Err(String::from(
+            "AssertLayout requires the template to be #[repr(C)]",
+        ));
+    }
+    if !generics.params.is_empty() {
+        return Err(String::from(
+            "AssertLayout doesn't support generic templates",
+        ));
+    }
+
+    let mut out = TokenStream::new();
+    let Some((first, others)) = variants.split_first() else {
+        return Ok(out);
+    };
+    let first_name = &first.derived.name;
+    for variant in others {
+        let name = &variant.derived.name;
+        for (field_number, field) in fields.iter().enumerate() {
+            if field.either.is_some()
+                || first.is_omitted(field_number, settings)
+                || variant.is_omitted(field_number, settings)
+            {
+                continue;
+            }
+            let member = position_member(field, field_number, first, settings);
+            let other_member = position_member(field, field_number, variant, settings);
+            let message = format!(
+                "Field \"{}\" of \"{}\" isn't at the same offset as in \"{}\"",
+                field.key, name, first_name
+            );
+            out.extend(quote! {
+                const _: () = ::core::assert!(
+                    ::core::mem::offset_of!(#name, #other_member)
+                        == ::core::mem::offset_of!(#first_name, #member),
+                    #message
+                );
+            });
+        }
+
+        let field_sizes = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| field.either.is_some())
+            .map(|(field_number, _)| {
+                let size_of = |variant: &Variant| match variant.is_omitted(field_number, settings) {
+                    true => quote! { 0 },
+                    false => {
+                        let ty = &variant.types[field_number];
+                        quote! { ::core::mem::size_of::<#ty>() }
+                    }
+                };
+                let (first_size, size) = (size_of(first), size_of(variant));
+                quote! { #first_size == #size }
+            });
+        let message = format!(
+            "\"{name}\" doesn't have the same size as \"{first_name}\", even though their fields do"
+        );
+        out.extend(quote! {
+            const _: () = ::core::assert!(
+                !(true #(&& #field_sizes)*)
+                    || ::core::mem::size_of::<#name>() == ::core::mem::size_of::<#first_name>(),
+                #message
+            );
+        });
+    }
+    Ok(out)
+}
+
+// How to refer to the field in `offset_of!`, since with omitted
+// fields the position of tuple struct fields changes
+pub(crate) fn position_member(
+    field: &TemplateField,
+    field_number: usize,
+    variant: &Variant,
+    settings: &Settings,
+) -> TokenStream {
+    match &field.field.ident {
+        Some(ident) => quote! { #ident },
+        None => {
+            let position = (0..field_number)
+                .filter(|n| !variant.is_omitted(*n, settings))
+                .count();
+            let index = syn::Index::from(position);
+            quote! { #index }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::Generics;
+
+use crate::{
+    helper,
+    minor_parsing::Settings,
+    template::{self, TemplateField, Variant},
+};
+
+// Without generated structs, derived structs with the same types are the
+// same type, which can't have a `new()` for each of their values
+pub(crate) fn check_shared_values(variants: &[Variant]) -> Result<(), String> {
+    let values = |variant: &Variant| {
+        let mut values: Vec<_> = variant
+            .derived
+            .values
+            .iter()
+            .map(|(key, value)| (key.clone(), value.to_token_stream().to_string()))
+            .collect();
+        values.sort();
+        values
+    };
+    for (position, variant) in variants.iter().enumerate() {
+        let same_type = variants[..position].iter().find(|other| {
+            other.types.iter().map(helper::pretty).collect::<Vec<_>>()
+                == variant.types.iter().map(helper::pretty).collect::<Vec<_>>()
+        });
+        if let Some(other) = same_type
+            && values(other) != values(variant)
+        {
+            return Err(format!(
+                "\"{}\" and \"{}\" are the same type, so they can't have different default values. Use GenStructs: true to make them different types",
+                other.derived.name, variant.derived.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Generates a `new()` constructor for every derived struct, taking only
+// the fields that have no default value, and a `Default` implementation
+// when none of the fields need to be provided, unless the template
+// (whose attributes the derived structs get) already derives it.
+//
+// Nothing is generated unless the template uses default values somewhere
+pub(crate) fn gen_constructors(
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+    is_tuple: bool,
+    attrs: &[syn::Attribute],
+) -> TokenStream {
+    let mut out = TokenStream::new();
+    if fields.iter().all(|field| field.default_value.is_none())
+        && variants
+            .iter()
+            .all(|variant| variant.derived.values.is_empty())
+    {
+        return out;
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    for variant in variants {
+        let mut params = vec![];
+        let mut values = vec![];
+        let mut idents = vec![];
+        for (field_number, (field, field_type)) in fields.iter().zip(&variant.types).enumerate() {
+            if variant.is_omitted(field_number, settings) {
+                continue;
+            }
+            idents.push(field.field.ident.clone());
+
+            let value = variant
+                .derived
+                .values
+                .get(&field.key)
+                .or(field.default_value.as_ref());
+            match value {
+                Some(value) => values.push(quote! { #value }),
+                None if template::is_empty_tuple(field_type) => values.push(quote! { () }),
+                None if template::is_absent(field_type) => {
+                    values.push(quote! { ::core::default::Default::default() })
+                }
+                None => {
+                    let param = match &field.field.ident {
+                        Some(ident) => ident.clone(),
+                        None => format_ident!("field_{}", field.key),
+                    };
+                    params.push(quote! { #param: #field_type });
+                    values.push(quote! { #param });
+                }
+            }
+        }
+
+        let body = match is_tuple {
+            true => quote! { Self(#(#values),*) },
+            false => quote! { Self { #(#idents: #values),* } },
+        };
+        let name = &variant.derived.name;
+        let vis = &variant.derived.vis;
+        let doc = format!("Creates a `{name}`, taking the fields without a default value");
+        out.extend(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #[doc = #doc]
+                #[allow(clippy::too_many_arguments)]
+                #vis fn new(#(#params),*) -> Self {
+                    #body
+                }
+            }
+        });
+
+        if params.is_empty() && !helper::derives(attrs, "Default") {
+            out.extend(quote! {
+                impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+                    fn default() -> Self {
+                        Self::new()
+                    }
+                }
+            });
+        }
+    }
+    out
+}
@@ -0,0 +1,244 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{GenericArgument, GenericParam, Ident, Token, Type, punctuated::Punctuated};
+
+use crate::{
+    docs, helper,
+    minor_parsing::AttrInputs,
+    report,
+    template::{self, TemplateField, Variant},
+};
+
+// This is the struct that handles parsing
+// the contents of the #[either()] attribute
+//
+// type, default type, ...
+struct EitherAttr(TokenStream);
+impl syn::parse::Parse for EitherAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut types = vec![];
+        while !input.is_empty() {
+            let mut option = TokenStream::new();
+            let fork = input.fork();
+            if let Ok(keyword) = fork.parse::<Ident>()
+                && keyword == "default"
+                && !fork.is_empty()
+                && !fork.peek(Token![,])
+                // `default::Type` is a path, not the marker
+                && !fork.peek(Token![::])
+            {
+                input.parse::<Ident>()?.to_tokens(&mut option);
+            }
+            input.parse::<Type>()?.to_tokens(&mut option);
+            types.push(option);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self(quote! { #(#types)|* }))
+    }
+}
+
+// The derived item turned into the template `make_template` would've gotten,
+// with the `#[either()]` fields using `either!` instead.
+//
+// `#[variant_attr(attribute)]` turns into `#[attribute]` on the derived structs.
+//
+// Also returns the contents of `#[variants()]`, and the
+// type every `#[either()]` field was declared with
+pub(crate) fn to_template(
+    mut template: syn::ItemStruct,
+) -> syn::Result<(syn::ItemStruct, TokenStream, Vec<Option<Type>>)> {
+    let mut attr = None;
+    let mut attrs = vec![];
+    for item_attr in std::mem::take(&mut template.attrs) {
+        match item_attr.path().is_ident("variants") {
+            true if attr.is_some() => {
+                return Err(syn::Error::new_spanned(
+                    item_attr,
+                    "Only one #[variants()] attribute can be used",
+                ));
+            }
+            true => attr = Some(item_attr.meta.require_list()?.tokens.clone()),
+            // derives never see the `#[derive()]` attributes, so the
+            // ones meant for the derived structs are listed separately
+            false if item_attr.path().is_ident("variant_attr") => {
+                let meta = item_attr.parse_args::<syn::Meta>()?;
+                attrs.push(syn::parse_quote! { #[#meta] });
+            }
+            false => attrs.push(item_attr),
+        }
+    }
+    template.attrs = attrs;
+    let Some(attr) = attr else {
+        return Err(syn::Error::new_spanned(
+            &template.ident,
+            "Expected a #[variants()] attribute listing the derived structs",
+        ));
+    };
+
+    let mut declared_types = vec![];
+    for field in template.fields.iter_mut() {
+        let mut either = None;
+        let mut attrs = vec![];
+        for field_attr in std::mem::take(&mut field.attrs) {
+            match field_attr.path().is_ident("either") {
+                true => either = Some(field_attr.parse_args::<EitherAttr>()?.0),
+                false => attrs.push(field_attr),
+            }
+        }
+        field.attrs = attrs;
+        match either {
+            Some(either) => {
+                let declared = std::mem::replace(
+                    &mut field.ty,
+                    syn::parse_quote! { ::either_field::either!(#either) },
+                );
+                declared_types.push(Some(declared));
+            }
+            None => declared_types.push(None),
+        }
+    }
+    Ok((template, attr, declared_types))
+}
+
+// Without GenStructs, the derived item can't be made generic, so the fields using
+// `#[either()]` have to be declared with one of its type parameters already.
+//
+// The derived structs become the item with those parameters filled in
+pub(crate) fn gen_derived_types(
+    template: &syn::ItemStruct,
+    fields: &[TemplateField],
+    declared_types: &[Option<Type>],
+    attribute_inputs: &AttrInputs,
+) -> Result<TokenStream, String> {
+    let settings = &attribute_inputs.settings;
+    if settings.generate_transitions
+        || settings.generate_maps
+        || settings.assert_layout
+        || settings.generate_views
+        || settings.generate_accessors
+        || settings.export_template
+        || settings.generate_traits
+        || !settings.assert_impl.is_empty()
+    {
+        return Err(String::from(
+            "Without GenStructs, #[derive(EitherVariants)] only generates type declarations, so it can't be used with GenTransitions, GenMaps, AssertLayout, GenViews, GenAccessors, ExportTemplate, GenTraits or AssertImpl",
+        ));
+    }
+    if fields.iter().any(|field| field.default_value.is_some())
+        || attribute_inputs
+            .derived_structs
+            .iter()
+            .any(|derived| !derived.values.is_empty())
+    {
+        return Err(String::from(
+            "Without GenStructs, #[derive(EitherVariants)] can't use default values",
+        ));
+    }
+
+    // which field every type parameter stands for
+    let mut parameters = vec![];
+    for (field, declared) in fields.iter().zip(declared_types) {
+        let Some(declared) = declared else {
+            continue;
+        };
+        match declared {
+            Type::Path(syn::TypePath { qself: None, path })
+                if path.get_ident().is_some_and(|ident| {
+                    template.generics.type_params().any(|x| x.ident == *ident)
+                }) =>
+            {
+                parameters.push((path.get_ident().unwrap().clone(), field.key.clone()));
+            }
+            _ => {
+                return Err(format!(
+                    "Field \"{}\" has to be declared with a type parameter of \"{}\", as it is turned into the types of #[either()] without GenStructs",
+                    field.key, template.ident
+                ));
+            }
+        }
+    }
+
+    let mut remaining = template.generics.clone();
+    remaining.params = template
+        .generics
+        .params
+        .iter()
+        .filter(|param| {
+            !matches!(param, GenericParam::Type(x) if parameters.iter().any(|(ident, _)| x.ident == *ident))
+        })
+        .cloned()
+        .collect();
+    if remaining.params.is_empty() {
+        remaining.lt_token = None;
+        remaining.gt_token = None;
+    }
+    let alias_generics = helper::without_bounds(&remaining);
+
+    let mut out = TokenStream::new();
+    let name = &template.ident;
+    let mut variants = vec![];
+    for derived in &attribute_inputs.derived_structs {
+        let variant = template::resolve(fields, derived, settings, false)?;
+        let args = alias_args(template, fields, &parameters, &variant);
+        let vis = &derived.vis;
+        let derived_name = &derived.name;
+        out.extend(docs::gen_variant_docs(name, fields, &variant, settings));
+        out.extend(quote! {
+            #vis type #derived_name #alias_generics = #name<#args>;
+        });
+        variants.push(variant);
+    }
+
+    if report::is_enabled(settings) {
+        let report = report::gen_report(name, fields, &variants, settings, |variant| {
+            format!(
+                "{} = {}<{}>",
+                variant.derived.name,
+                name,
+                helper::pretty(&alias_args(template, fields, &parameters, variant))
+            )
+        });
+        report::write_report(name, &report)?;
+    }
+    Ok(out)
+}
+
+// The arguments of the item a derived struct is a type declaration of,
+// `parameters` being which field every type parameter stands for
+fn alias_args(
+    template: &syn::ItemStruct,
+    fields: &[TemplateField],
+    parameters: &[(Ident, String)],
+    variant: &Variant,
+) -> Punctuated<GenericArgument, Token![,]> {
+    template
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(x) => GenericArgument::Lifetime(x.lifetime.clone()),
+            GenericParam::Const(x) => {
+                let ident = &x.ident;
+                GenericArgument::Type(syn::parse_quote! { #ident })
+            }
+            GenericParam::Type(x) => {
+                let key = parameters
+                    .iter()
+                    .find(|(ident, _)| x.ident == *ident)
+                    .map(|(_, key)| key);
+                match key.and_then(|key| fields.iter().position(|field| field.key == *key)) {
+                    Some(field_number) => {
+                        GenericArgument::Type(variant.types[field_number].clone())
+                    }
+                    None => {
+                        let ident = &x.ident;
+                        GenericArgument::Type(syn::parse_quote! { #ident })
+                    }
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Ident, braced, parse::Parse};
+
+use crate::{minor_parsing::AttrInputs, naming, template};
+
+// The name of the macro describing the template
+pub(crate) fn descriptor_name(template: &Ident) -> Ident {
+    format_ident!("__either_field_{}", template, span = Span::call_site())
+}
+
+// Every other macro working on a template needs to know its fields and derived
+// structs, but macros can't see other items. So, next to the template, we leave
+// a declarative macro that hands the template's original tokens to a callback:
+//
+// descriptor! { [path::to::callback] payload }
+//
+// expands to
+//
+// path::to::callback! { { payload } { attribute inputs } { template } }
+//
+// With ExportTemplate, the descriptor is exported so other crates can use it too
+pub(crate) fn gen_descriptor(
+    template: &Ident,
+    attr: TokenStream,
+    item: TokenStream,
+    exported: bool,
+) -> TokenStream {
+    let name = descriptor_name(template);
+    let export = exported.then(|| quote! { #[macro_export] });
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        #export
+        macro_rules! #name {
+            ([$($callback:tt)*] $($payload:tt)*) => {
+                $($callback)*! { { $($payload)* } { #attr } { #item } }
+            };
+        }
+    }
+}
+
+// This is the struct that handles parsing
+// what the descriptor hands to the callback
+//
+// { payload } { attribute inputs } { template }
+pub(crate) struct Described<T: Parse> {
+    pub payload: T,
+    pub attribute_inputs: AttrInputs,
+    pub template: syn::ItemStruct,
+    pub is_union: bool,
+}
+impl<T: Parse> Parse for Described<T> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let payload;
+        let attribute_inputs;
+        let template;
+        braced!(payload in input);
+        braced!(attribute_inputs in input);
+        braced!(template in input);
+        let (template, is_union) = template::from_item(template.parse()?)?;
+        let mut attribute_inputs = attribute_inputs.parse()?;
+        naming::name_derived_structs(&mut attribute_inputs, &template.ident, &template.fields)?;
+        Ok(Self {
+            payload: payload.parse()?,
+            attribute_inputs,
+            template,
+            is_union,
+        })
+    }
+}
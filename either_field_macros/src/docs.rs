@@ -0,0 +1,66 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Type;
+
+use crate::{
+    helper::pretty,
+    minor_parsing::{Derived, Settings},
+    template::{TemplateField, Variant},
+};
+
+pub(crate) fn is_doc(attr: &syn::Attribute) -> bool {
+    attr.path().is_ident("doc")
+}
+
+// The doc comments written on the derived struct, followed by
+// what it's derived from and the type every field was given
+//
+// `fields` are the field keys with their types, or `None` if omitted
+pub(crate) fn gen_docs<'a>(
+    derived: &Derived,
+    template: &str,
+    fields: impl IntoIterator<Item = (String, Option<&'a Type>)>,
+) -> TokenStream {
+    let mut lines = vec![];
+    let fields: Vec<_> = fields.into_iter().collect();
+    match fields.is_empty() {
+        true => lines.push(format!(" Derived from `{template}`.")),
+        false => lines.push(format!(" Derived from `{template}`, with:")),
+    }
+    for (key, ty) in fields {
+        lines.push(match ty {
+            Some(ty) => format!(" - `{key}`: `{}`", pretty(ty)),
+            None => format!(" - `{key}` omitted"),
+        });
+    }
+
+    let docs = &derived.docs;
+    let separator = (!docs.is_empty()).then(|| quote! { #[doc = ""] });
+    quote! {
+        #(#docs)*
+        #separator
+        #(#[doc = #lines])*
+    }
+}
+
+// The docs of a derived struct of a template, listing the `either!` fields
+pub(crate) fn gen_variant_docs(
+    template: &syn::Ident,
+    fields: &[TemplateField],
+    variant: &Variant,
+    settings: &Settings,
+) -> TokenStream {
+    gen_docs(
+        variant.derived,
+        &template.to_string(),
+        fields
+            .iter()
+            .zip(&variant.types)
+            .enumerate()
+            .filter(|(_, (field, _))| field.either.is_some())
+            .map(|(field_number, (field, ty))| {
+                let ty = (!variant.is_omitted(field_number, settings)).then_some(ty);
+                (field.key.clone(), ty)
+            }),
+    )
+}
@@ -0,0 +1,154 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Token, parenthesized, parse::Parse};
+
+use crate::{
+    descriptor, docs, helper,
+    minor_parsing::{AttrInputs, Settings},
+    template::{self, TemplateField},
+};
+
+// This is the struct that handles parsing the input of extend_template!
+//
+// path::to::Template => attribute input
+pub(crate) struct ExtendInput {
+    pub path: syn::Path,
+    pub attr: TokenStream,
+}
+impl Parse for ExtendInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path = input.parse::<syn::Path>()?;
+        let _ = input.parse::<Token![=>]>()?;
+        Ok(Self {
+            path,
+            attr: input.parse()?,
+        })
+    }
+}
+
+// What gets passed through the template's descriptor
+//
+// (path::to::Template) attribute input
+pub(crate) struct ExtendPayload {
+    pub path: syn::Path,
+    pub attribute_inputs: AttrInputs,
+}
+impl Parse for ExtendPayload {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path;
+        parenthesized!(path in input);
+        Ok(Self {
+            path: path.parse()?,
+            attribute_inputs: input.parse()?,
+        })
+    }
+}
+
+// Exported macros live at the root of the crate defining them,
+// so the descriptor of `krate::path::Template` is `krate::__either_field_Template`
+pub(crate) fn descriptor_path(path: &syn::Path) -> syn::Result<TokenStream> {
+    let Some(template) = path.segments.last() else {
+        return Err(syn::Error::new_spanned(
+            path,
+            "Expected the path to a template",
+        ));
+    };
+    let descriptor = descriptor::descriptor_name(&template.ident);
+    match path.segments.len() {
+        1 => Ok(quote! { #descriptor }),
+        _ => {
+            let leading_colon = &path.leading_colon;
+            let krate = &path.segments[0].ident;
+            Ok(quote! { #leading_colon #krate::#descriptor })
+        }
+    }
+}
+
+// The settings of the new derived structs: how they're generated is up to
+// the template, only RequireAllFields can be set by whoever extends it
+pub(crate) fn extended_settings(
+    template: &Settings,
+    extension: &Settings,
+) -> Result<Settings, String> {
+    if extension.generate_structs
+        || extension.delete_template
+        || extension.delete_empty_tuple_fields
+        || extension.omit_absent_fields
+        || extension.generate_transitions
+        || extension.generate_maps
+        || extension.assert_layout
+        || extension.generate_views
+        || extension.generate_accessors
+        || extension.generate_traits
+        || extension.export_template
+        || extension.report
+        || extension.naming.is_some()
+        || extension.module.is_some()
+        || !extension.reexport.is_empty()
+        || extension.changes_template()
+        || !extension.assert_impl.is_empty()
+    {
+        return Err(String::from(
+            "Only RequireAllFields can be set when extending a template, the other settings come from the template",
+        ));
+    }
+    Ok(Settings {
+        generate_structs: template.generate_structs,
+        delete_template: true,
+        delete_empty_tuple_fields: template.delete_empty_tuple_fields,
+        omit_absent_fields: template.omit_absent_fields,
+        require_all_fields: extension.require_all_fields,
+        generate_transitions: template.generate_transitions,
+        generate_maps: false,
+        assert_layout: template.assert_layout,
+        generate_views: template.generate_views,
+        // the traits belong to the crate of the template
+        generate_accessors: false,
+        generate_traits: template.generate_traits,
+        export_template: false,
+        report: false,
+        naming: template.naming.clone(),
+        // the template's module is in the crate of the template
+        module: None,
+        reexport: vec![],
+        hide_template: false,
+        template_vis: None,
+        rename_template: None,
+        assert_impl: template.assert_impl.clone(),
+    })
+}
+
+// In type declaration mode the template is already generic over its fields,
+// so the new derived structs are declared as instances of it
+pub(crate) fn gen_extended_types(
+    path: &syn::Path,
+    template: &syn::ItemStruct,
+    fields: &[TemplateField],
+    attribute_inputs: &AttrInputs,
+) -> Result<TokenStream, String> {
+    let mut out = TokenStream::new();
+    let generic_names: Vec<_> = template.generics.type_params().map(|x| &x.ident).collect();
+    let generics = helper::without_bounds(&template.generics);
+    for derived in &attribute_inputs.derived_structs {
+        let variant = template::resolve(fields, derived, &attribute_inputs.settings, false)?;
+        // this has to match the order of the generics
+        let types = fields
+            .iter()
+            .zip(&variant.types)
+            .filter(|(field, _)| field.either.is_some())
+            .map(|(_, t)| t);
+
+        let vis = &derived.vis;
+        let name = &derived.name;
+        out.extend(docs::gen_variant_docs(
+            &template.ident,
+            fields,
+            &variant,
+            &attribute_inputs.settings,
+        ));
+        out.extend(quote! {
+            #vis type #name #generics = #path<#(#generic_names,)* #(#types),*>;
+        });
+    }
+    Ok(out)
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{GenericParam, Generics, Ident, Macro, Type, ext::IdentExt};
+
+// Every identifier mentioned anywhere inside the template, so that
+// the generated generic parameters can't shadow any of them
+pub(crate) fn collect_idents(tokens: TokenStream, out: &mut HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                out.insert(ident.unraw().to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), out),
+            _ => (),
+        }
+    }
+}
+
+// Tokens printed as they'd usually be written,
+// i.e. `Vec<u8>` rather than `Vec < u8 >`
+pub(crate) fn pretty(tokens: &impl ToTokens) -> String {
+    let mut out = tokens.to_token_stream().to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" ;", ";"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        ("& ", "&"),
+    ] {
+        out = out.replace(from, to);
+    }
+    out
+}
+
+pub(crate) fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (index, character) in name.chars().enumerate() {
+        if character.is_uppercase() {
+            if index != 0 {
+                out.push('_');
+            }
+            out.extend(character.to_lowercase());
+        } else {
+            out.push(character);
+        }
+    }
+    out
+}
+
+// The name is derived from the field's name (or position, for tuple structs)
+// so it stays the same when other fields are added, removed or reordered
+pub(crate) fn generate_generic_name(field_key: &str, taken: &mut HashSet<String>) -> Ident {
+    let mut new_generic_name = format!("__EitherField{}", to_camel_case(field_key));
+    while taken.contains(&new_generic_name) {
+        new_generic_name.push('_');
+    }
+    taken.insert(new_generic_name.clone());
+    Ident::new(&new_generic_name, Span::call_site())
+}
+
+// Bounds on type aliases aren't enforced, and only cause warnings
+pub(crate) fn without_bounds(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.params.iter_mut() {
+        match param {
+            GenericParam::Lifetime(x) => {
+                x.colon_token = None;
+                x.bounds.clear();
+            }
+            GenericParam::Type(x) => {
+                x.colon_token = None;
+                x.bounds.clear();
+            }
+            GenericParam::Const(_) => (),
+        }
+    }
+    generics.where_clause = None;
+    generics
+}
+
+// The bounds of a type parameter, which a new parameter
+// standing for the same field has to have too
+pub(crate) fn bounds_of(generics: &Generics, ident: &Ident) -> TokenStream {
+    match generics.type_params().find(|param| param.ident == *ident) {
+        Some(param) => param.bounds.to_token_stream(),
+        None => TokenStream::new(),
+    }
+}
+
+// TODO: need a better way to check whether the macro
+// is the correct one and not one with the same name
+pub(crate) fn get_macro_from_type(x: &Type) -> Option<Macro> {
+    if let Type::Macro(x) = x
+        && x.mac
+            .path
+            .segments
+            .iter()
+            .any(|segment| segment.ident == "either")
+    {
+        return Some(x.mac.clone());
+    }
+    None
+}
+
+// Whether `#[derive(...)]` among the attributes includes `name`,
+// whichever path it's written with
+pub(crate) fn derives(attrs: &[syn::Attribute], name: &str) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("derive")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .is_ok_and(|paths| {
+                    paths.iter().any(|path| {
+                        path.segments
+                            .last()
+                            .is_some_and(|segment| segment.ident == name)
+                    })
+                })
+    })
+}
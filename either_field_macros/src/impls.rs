@@ -0,0 +1,275 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, Type, bracketed, ext::IdentExt, parenthesized, parse::Parse,
+    punctuated::Punctuated, visit_mut::VisitMut,
+};
+
+use crate::{
+    descriptor::{self, Described},
+    helper,
+    minor_parsing::Derived,
+    template::{self, TemplateField, Variant},
+};
+
+// This is the struct that handles parsing the
+// input of the template_impl attribute
+//
+// Template
+// Template: [Variant, ...]
+pub(crate) struct ImplTarget {
+    pub template: Ident,
+    pub only: Option<Vec<Ident>>,
+}
+impl Parse for ImplTarget {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let template = input.parse::<Ident>()?;
+        let only = match input.peek(Token![:]) {
+            true => {
+                let _ = input.parse::<Token![:]>()?;
+                let list;
+                bracketed!(list in input);
+                let parsed: Punctuated<Ident, Token![,]> = Punctuated::parse_terminated(&list)?;
+                Some(parsed.into_iter().collect())
+            }
+            false => None,
+        };
+        Ok(Self { template, only })
+    }
+}
+
+// What gets passed through the template's descriptor
+//
+// (attribute input) impl ... { ... }
+pub(crate) struct ImplPayload<T: Parse> {
+    pub target: T,
+    pub item: syn::ItemImpl,
+}
+impl<T: Parse> Parse for ImplPayload<T> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let target;
+        parenthesized!(target in input);
+        Ok(Self {
+            target: target.parse()?,
+            item: input.parse()?,
+        })
+    }
+}
+
+// Hands the impl block to the template's descriptor,
+// which calls `callback` back with everything needed
+pub(crate) fn forward_to_descriptor(
+    template: &Ident,
+    callback: TokenStream,
+    attr: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let descriptor = descriptor::descriptor_name(template);
+    quote! {
+        #descriptor! { [#callback] (#attr) #item }
+    }
+}
+
+// Replaces every `either!(field)` inside the impl
+// with the type the field has in the variant
+struct FieldTypes<'a> {
+    fields: &'a [TemplateField],
+    types: &'a [Type],
+    errors: Vec<syn::Error>,
+}
+impl VisitMut for FieldTypes<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Some(type_macro) = helper::get_macro_from_type(ty) {
+            let key = match syn::parse2::<syn::Member>(type_macro.tokens.clone()) {
+                Ok(syn::Member::Named(ident)) => ident.unraw().to_string(),
+                Ok(syn::Member::Unnamed(index)) => index.index.to_string(),
+                Err(e) => {
+                    self.errors.push(e);
+                    return;
+                }
+            };
+            match self.fields.iter().position(|field| field.key == key) {
+                Some(field_number) => *ty = self.types[field_number].clone(),
+                None => self.errors.push(syn::Error::new_spanned(
+                    type_macro.tokens,
+                    format!("The template has no field \"{key}\""),
+                )),
+            }
+            return;
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+// Emits a copy of the impl block for the variant, with
+// its self type renamed and the field types substituted
+//
+// With a Module, the variant is found in it, next to the template
+pub(crate) fn impl_for_variant(
+    item: &syn::ItemImpl,
+    template_ident: &Ident,
+    fields: &[TemplateField],
+    variant: &Variant,
+    module: Option<&Ident>,
+) -> syn::Result<TokenStream> {
+    let mut item = item.clone();
+    match item.self_ty.as_mut() {
+        Type::Path(syn::TypePath { qself: None, path })
+            if path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == *template_ident) =>
+        {
+            let mut segment = path.segments.pop().unwrap().into_value();
+            segment.ident = variant.derived.name.clone();
+            if let Some(module) = module {
+                path.segments.push(module.clone().into());
+            }
+            path.segments.push(segment);
+        }
+        other => {
+            return Err(syn::Error::new_spanned(
+                other,
+                format!("Expected an impl block for \"{template_ident}\""),
+            ));
+        }
+    }
+
+    let mut visitor = FieldTypes {
+        fields,
+        types: &variant.types,
+        errors: vec![],
+    };
+    visitor.visit_item_impl_mut(&mut item);
+    match visitor.errors.into_iter().reduce(|mut a, b| {
+        a.combine(b);
+        a
+    }) {
+        Some(e) => Err(e),
+        None => Ok(item.into_token_stream()),
+    }
+}
+
+// Re-emits the impl block for every variant `keep` agrees on
+pub(crate) fn impl_for_variants(
+    described: Described<ImplPayload<impl Parse>>,
+    mut keep: impl FnMut(&Variant, &[TemplateField]) -> syn::Result<bool>,
+) -> syn::Result<TokenStream> {
+    let Described {
+        payload,
+        attribute_inputs,
+        mut template,
+        ..
+    } = described;
+    let settings = &attribute_inputs.settings;
+    let fields = template::parse_fields(&mut template.fields)?;
+
+    // the errors here are already reported by make_template
+    let variants: Vec<_> = attribute_inputs
+        .derived_structs
+        .iter()
+        .filter_map(|derived| {
+            template::resolve(&fields, derived, settings, settings.generate_structs).ok()
+        })
+        .collect();
+
+    let mut kept = vec![];
+    for variant in variants {
+        if keep(&variant, &fields)? {
+            kept.push(variant);
+        }
+    }
+
+    let mut out = TokenStream::new();
+    for variant in template::distinct(&kept, settings) {
+        out.extend(impl_for_variant(
+            &payload.item,
+            &template.ident,
+            &fields,
+            variant,
+            settings.module.as_ref().map(|(_, name)| name),
+        )?);
+    }
+    Ok(out)
+}
+
+pub(crate) fn template_impl(
+    described: Described<ImplPayload<ImplTarget>>,
+) -> syn::Result<TokenStream> {
+    let only = described.payload.target.only.clone();
+    if let Some(only) = &only {
+        for name in only {
+            if !described
+                .attribute_inputs
+                .derived_structs
+                .iter()
+                .any(|derived| derived.name == *name)
+            {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        "\"{}\" is not a derived struct of \"{}\"",
+                        name, described.template.ident
+                    ),
+                ));
+            }
+        }
+    }
+
+    impl_for_variants(described, |variant, _| {
+        Ok(match &only {
+            Some(only) => only.contains(&variant.derived.name),
+            None => true,
+        })
+    })
+}
+
+// Only keeps the variants whose fields have the types
+// specified in the predicate, written like a derived struct
+//
+// Template: [field_name: type, ...]
+pub(crate) fn variant_impl(described: Described<ImplPayload<Derived>>) -> syn::Result<TokenStream> {
+    let predicate = &described.payload.target;
+    if predicate.name != described.template.ident {
+        return Err(syn::Error::new(
+            predicate.name.span(),
+            format!("Expected the template \"{}\"", described.template.ident),
+        ));
+    }
+
+    let predicate_fields = predicate.fields.clone();
+    let template_name = described.template.ident.clone();
+    let mut matched = false;
+    let out = impl_for_variants(described, |variant, fields| {
+        for (key, ty) in &predicate_fields {
+            let Some(field_number) = fields.iter().position(|field| field.key == *key) else {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    format!("The template has no field \"{key}\""),
+                ));
+            };
+            let expected = match (ty, &fields[field_number].either) {
+                (Type::Infer(_), Some(either)) => either.default_type(),
+                _ => ty,
+            };
+            if variant.types[field_number] != *expected {
+                return Ok(false);
+            }
+        }
+        matched = true;
+        Ok(true)
+    })?;
+
+    match matched {
+        true => Ok(out),
+        false => Err(syn::Error::new(
+            Span::call_site(),
+            format!("No derived struct of \"{template_name}\" has fields of the specified types"),
+        )),
+    }
+}
+
+pub(crate) fn callback_path(name: &str) -> TokenStream {
+    let name = Ident::new(name, Span::call_site());
+    quote! { ::either_field::#name }
+}
@@ -0,0 +1,174 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident, Type};
+
+use crate::{
+    assertions,
+    minor_parsing::Settings,
+    template::{TemplateField, Variant},
+    transitions::{self, generic_args},
+};
+
+fn field_id(field: &TemplateField) -> TokenStream {
+    let key = &field.key;
+    quote! { { ::either_field::field_id(#key) } }
+}
+
+// `HasField` for every field of a struct,
+// `fields` being the ones it actually has with their types
+fn has_fields(
+    name: &TokenStream,
+    generics: &Generics,
+    fields: &[(&TemplateField, &Type)],
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let impls = fields.iter().enumerate().map(|(position, (field, ty))| {
+        let id = field_id(field);
+        let access = transitions::access(field, position);
+        quote! {
+            impl #impl_generics ::either_field::HasField<#id> for #name #where_clause {
+                type Type = #ty;
+                fn field(&self) -> &Self::Type {
+                    &#access
+                }
+                fn field_mut(&mut self) -> &mut Self::Type {
+                    &mut #access
+                }
+            }
+        }
+    });
+    quote! { #(#impls)* }
+}
+
+// `Template` on the template, and `VariantOf` on the derived structs,
+// which are all named with the arguments of `generics`
+fn variants_of(
+    template: &TokenStream,
+    variants: &[TokenStream],
+    generics: &Generics,
+) -> TokenStream {
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::either_field::Template for #template #where_clause {
+            type Variants = (#(::core::marker::PhantomData<#variants>,)*);
+        }
+        #(
+            impl #impl_generics ::either_field::VariantOf<#template> for #variants #where_clause {}
+        )*
+    }
+}
+
+// In type declaration mode, the template (with every field using its default type)
+// is what the traits are about, and every instance of it has all the fields.
+//
+// If a field defaults to a bound, only `HasField` is implemented,
+// and for `#[repr(packed)]` templates, whose fields can't be borrowed, only the others
+//
+// `template` is the template after its fields have been turned into generics
+pub(crate) fn gen_generic_traits(
+    template: &syn::ItemStruct,
+    initial_generics: &Generics,
+    fields: &[TemplateField],
+    variants: &[&Variant],
+) -> TokenStream {
+    let name = &template.ident;
+    let initial_args = initial_generics.params.iter().map(|param| match param {
+        syn::GenericParam::Lifetime(x) => {
+            let lifetime = &x.lifetime;
+            quote! { #lifetime }
+        }
+        syn::GenericParam::Type(syn::TypeParam { ident, .. })
+        | syn::GenericParam::Const(syn::ConstParam { ident, .. }) => quote! { #ident },
+    });
+    let default_types = fields
+        .iter()
+        .filter_map(|field| field.either.as_ref())
+        .map(|either| either.default_type());
+    let default_template = quote! { #name<#(#initial_args,)* #(#default_types),*> };
+
+    let variant_args = generic_args(initial_generics, None);
+    let variant_names: Vec<_> = variants
+        .iter()
+        .map(|variant| {
+            let variant_name = &variant.derived.name;
+            quote! { #variant_name #variant_args }
+        })
+        .collect();
+
+    let template_fields: Vec<_> = fields
+        .iter()
+        .zip(template.fields.iter().map(|x| &x.ty))
+        .collect();
+    let template_args = generic_args(&template.generics, None);
+    let mut out = TokenStream::new();
+    // without a default type for every field, there's no template to refer to
+    if fields
+        .iter()
+        .filter_map(|field| field.either.as_ref())
+        .all(|either| either.has_default_type())
+    {
+        out.extend(variants_of(
+            &default_template,
+            &variant_names,
+            initial_generics,
+        ));
+    }
+    if !assertions::has_repr(&template.attrs, "packed") {
+        out.extend(has_fields(
+            &quote! { #name #template_args },
+            &template.generics,
+            &template_fields,
+        ));
+    }
+    out
+}
+
+// With generated structs, the template is only there to implement
+// `Template` if it's kept, while every derived struct gets its own fields,
+// unless they're `#[repr(packed)]`
+pub(crate) fn gen_struct_traits(
+    template_ident: &Ident,
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+    has_template: bool,
+    is_packed: bool,
+) -> TokenStream {
+    let args = generic_args(generics, None);
+    let mut out = TokenStream::new();
+    if has_template {
+        let variant_names: Vec<_> = variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.derived.name;
+                quote! { #variant_name #args }
+            })
+            .collect();
+        out.extend(variants_of(
+            &quote! { #template_ident #args },
+            &variant_names,
+            generics,
+        ));
+    }
+
+    if is_packed {
+        return out;
+    }
+    for variant in variants {
+        let variant_fields: Vec<_> = fields
+            .iter()
+            .zip(&variant.types)
+            .enumerate()
+            .filter(|(field_number, _)| !variant.is_omitted(*field_number, settings))
+            .map(|(_, field)| field)
+            .collect();
+        let variant_name = &variant.derived.name;
+        out.extend(has_fields(
+            &quote! { #variant_name #args },
+            generics,
+            &variant_fields,
+        ));
+    }
+    out
+}
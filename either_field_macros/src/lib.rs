@@ -0,0 +1,1118 @@
+use std::hint::unreachable_unchecked;
+
+use proc_macro::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{GenericParam, Generics, Type, parse_macro_input, punctuated::Punctuated, token::Comma};
+
+mod accessors;
+mod assertions;
+mod constructors;
+mod derive;
+mod descriptor;
+mod docs;
+mod export;
+mod helper;
+mod impls;
+mod introspection;
+mod maps;
+mod minor_parsing;
+mod module;
+mod naming;
+mod report;
+mod template;
+mod transitions;
+mod variants;
+mod views;
+
+macro_rules! custom_compiler_error_msg {
+    ($out: ident, $format: literal) => {
+            let error_message = String::from($format);
+            $out.extend::<proc_macro2::TokenStream>(quote! {  compile_error!(#error_message); });
+        };
+    ($out: ident, $format: literal, $($arg:expr),*) => {
+            let error_message = format!($format, $($arg),*);
+            $out.extend::<proc_macro2::TokenStream>(quote! {  compile_error!(#error_message); });
+        };
+}
+
+/// The meat and bone of the crate
+///
+/// This will turn any template struct, i.e:
+/// ```ignore
+/// #[make_template(/* ... */)]
+/// struct ThisIsAnExample {
+///     field_1: either!(() | i32),
+///     field_2: either!(() | String)
+/// }
+/// ```
+/// into all the variants defined in the attribute input
+/// ```
+/// # use either_field::{either, make_template};
+/// #[make_template(
+///     DerivateOne: [
+///         field_1: i32
+///     ],
+///     DerivateTwo: [
+///         field_2: String
+///     ],
+///     DerivateThree: [
+///         field_1: i32,
+///         field_2: String
+///     ]
+/// )]
+/// struct ThisIsAnExample {
+/// #   field_1: either!(() | i32),
+/// #   field_2: either!(() | String)
+///     /* ... */
+/// }
+/// ```
+/// which will effectively turn to the following code
+/// ```
+/// struct ThisIsAnExample<__EitherFieldField1, __EitherFieldField2> {
+///     field_1: __EitherFieldField1,
+///     field_2: __EitherFieldField2
+/// }
+/// type DerivateOne = ThisIsAnExample<i32, ()>;
+/// type DerivateTwo = ThisIsAnExample<(), String>;
+/// type DerivateThree = ThisIsAnExample<i32, String>;
+/// ```
+/// Tuple structs work the same way, with fields referred to by their position
+/// (i.e. `0: i32`) or listed in order (i.e. `[i32, _]`). Unions can also be templates,
+/// as long as every member they end up with is `Copy` or `ManuallyDrop`.
+///
+/// The generated generic parameters are named after the field they stand for,
+/// and never shadow a name already used inside the template.
+///
+/// With `GenTraits: true`, every template and derived struct implements the traits of `either_field`
+/// (`Template`, `VariantOf` and `HasField`), for generic code to use.
+///
+/// Every unspecified field (or field set to `_`) will use the first argument
+/// of [`macro@either`] as default, unless another one is marked with `default`:
+/// ```
+/// # use either_field::{either, make_template};
+/// # #[make_template(Derivate: [_])]
+/// # struct ThisIsAnExample {
+/// field_1: either!(() | default i32)
+/// # }
+/// # let _: Derivate = ThisIsAnExample { field_1: 0 };
+/// ```
+/// Setting `RequireAllFields: true` instead turns unspecified fields into an error.
+///
+/// One of the types can be a bound, such as `either!(() | impl Into<String>)`,
+/// in which case any type satisfying it can be picked.
+///
+/// Fields can have default values, with `#[default(expr)]` on the template's field
+/// (unless it uses `either!`) or `field_1: i32 = expr` in a derived struct. If any are used,
+/// every derived struct gets a `new()` constructor taking the fields without one, and
+/// implements [`Default`] if there are none left and the template doesn't derive it.
+///
+/// Because the syntax is JSON-like, a common error is having extra commas.
+#[proc_macro_attribute]
+pub fn make_template(
+    attr: proc_macro::TokenStream,
+    items: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    expand_template(attr, items)
+}
+
+/// Declares derived structs without an attribute on the template
+///
+/// This is useful when the template can't have attributes, because it's defined by
+/// another macro or lives in another crate. There are two forms:
+/// - a whole template, followed by `=>` and the input of [`macro@make_template`],
+///   which behaves as if the attribute was placed on it
+/// ```
+/// # use either_field::{either, variants};
+/// # #[derive(Debug)]
+/// # struct PlayerData;
+/// variants! {
+///     #[derive(Debug)]
+///     struct Score {
+///         player: either!(i32 | PlayerData),
+///         value: i32
+///     }
+///     => ScoreWithPlayer: [player: PlayerData]
+/// }
+/// ```
+/// - an existing generic type, naming each of its type parameters after the field
+///   it stands for (optionally with a default type), followed by `=>` and the derived
+///   structs, which become type declarations
+/// ```
+/// # use either_field::variants;
+/// # struct PlayerData;
+/// # mod proto {
+/// #     pub struct Score<P, V> {
+/// #         pub player: P,
+/// #         pub value: V,
+/// #     }
+/// # }
+/// variants! {
+///     type proto::Score<player, value = i32>
+///     => ScoreWithId: [player: i32], ScoreWithPlayer: [player: PlayerData]
+/// }
+/// ```
+#[proc_macro]
+pub fn variants(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match parse_macro_input!(input as variants::VariantsInput) {
+        variants::VariantsInput::Template { attr, item } => {
+            expand_template(attr.into(), item.into())
+        }
+        variants::VariantsInput::Existing(existing) => match existing.expand() {
+            Ok(v) => v.into(),
+            Err(e) => e.into_compile_error().into(),
+        },
+    }
+}
+
+fn expand_template(
+    attr: proc_macro::TokenStream,
+    items: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut out = proc_macro2::TokenStream::new();
+
+    let items_clone = items.clone();
+    let (mut template_struct, is_union) =
+        match template::from_item(parse_macro_input!(items_clone as syn::Item)) {
+            Ok(v) => v,
+            Err(e) => return e.into_compile_error().into(),
+        };
+    let attr_clone = attr.clone();
+    let mut attribute_inputs = parse_macro_input!(attr_clone as minor_parsing::AttrInputs);
+    if let Err(e) = naming::name_derived_structs(
+        &mut attribute_inputs,
+        &template_struct.ident,
+        &template_struct.fields,
+    ) {
+        return e.into_compile_error().into();
+    }
+    out.extend(descriptor::gen_descriptor(
+        &template_struct.ident,
+        attr.into(),
+        items.into(),
+        attribute_inputs.settings.export_template,
+    ));
+
+    if is_union {
+        check_union(&mut out, &template_struct, &attribute_inputs);
+    }
+
+    // with a Module, everything generated from here on goes inside of it
+    let settings = &attribute_inputs.settings;
+    let keeps_template = !settings.generate_structs || !settings.delete_template;
+    let module = module::Module::take(&mut template_struct, &mut attribute_inputs, keeps_template);
+    let mut parent = proc_macro2::TokenStream::new();
+    if module.is_some() {
+        std::mem::swap(&mut parent, &mut out);
+    }
+
+    let generated = match (
+        &template_struct.fields,
+        attribute_inputs.settings.generate_structs,
+    ) {
+        (syn::Fields::Unit, _) => {
+            custom_compiler_error_msg!(out, "Unit structs have no fields to do anything about.");
+            out.into()
+        }
+        (_, false) => gen_types(out, template_struct, attribute_inputs, is_union),
+        (syn::Fields::Named(_), true) => {
+            gen_structs(out, template_struct, attribute_inputs, false, is_union)
+        }
+        (syn::Fields::Unnamed(_), true) => {
+            gen_structs(out, template_struct, attribute_inputs, true, is_union)
+        }
+    };
+    match module {
+        Some(module) => {
+            parent.extend(module.wrap(generated.into()));
+            parent.into()
+        }
+        None => generated,
+    }
+}
+
+fn check_union(
+    out: &mut proc_macro2::TokenStream,
+    template_struct: &syn::ItemStruct,
+    attribute_inputs: &minor_parsing::AttrInputs,
+) {
+    let settings = &attribute_inputs.settings;
+    if settings.generate_transitions || settings.generate_maps || settings.generate_accessors {
+        custom_compiler_error_msg!(
+            out,
+            "Unions can't be used with GenTransitions, GenMaps or GenAccessors, as their fields can't be moved out"
+        );
+    }
+    if attribute_inputs
+        .derived_structs
+        .iter()
+        .any(|derived| !derived.values.is_empty())
+        || template_struct.fields.iter().any(|field| {
+            field
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("default"))
+        })
+    {
+        custom_compiler_error_msg!(out, "Unions can't have default values");
+    }
+}
+
+fn gen_types(
+    mut out: proc_macro2::TokenStream,
+    mut template_struct: syn::ItemStruct,
+    attribute_inputs: minor_parsing::AttrInputs,
+    is_union: bool,
+) -> TokenStream {
+    if attribute_inputs.settings.changes_template() {
+        custom_compiler_error_msg!(
+            out,
+            "HideTemplate, TemplateVis and RenameTemplate only apply to the template kept with GenStructs"
+        );
+    }
+    let fields = match template::parse_fields(&mut template_struct.fields) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let initial_generics: Generics = template_struct.generics.clone();
+    let mut taken_idents = std::collections::HashSet::new();
+    helper::collect_idents(template_struct.to_token_stream(), &mut taken_idents);
+    let derives_serde = template::derives_serde(&template_struct.attrs);
+    let generics = &mut template_struct.generics.params;
+
+    for (field, template_field) in template_struct.fields.iter_mut().zip(&fields) {
+        let Some(either) = &template_field.either else {
+            continue;
+        };
+
+        // generic union members have to be known to be `Copy`
+        let mut bounds = Punctuated::new();
+        if is_union {
+            if either.types.iter().any(template::is_manually_drop) {
+                custom_compiler_error_msg!(
+                    out,
+                    "Union member \"{}\" can be ManuallyDrop, which requires GenStructs to be true",
+                    template_field.key
+                );
+                return out.into();
+            }
+            bounds.push(syn::parse_quote!(::core::marker::Copy));
+        }
+        if let Some(bound) = either.bound()
+            && either.is_only_bound()
+        {
+            bounds.extend(bound.bounds.iter().cloned());
+        }
+
+        // `Absent` fields are left out when serializing, which has to be checked
+        // at runtime, since the field's type is a generic
+        if derives_serde && either.types.iter().any(template::is_absent) {
+            field.attrs.push(syn::parse_quote! {
+                #[serde(skip_serializing_if = "::either_field::Absent::is_absent")]
+            });
+        }
+
+        let ident = helper::generate_generic_name(&template_field.key, &mut taken_idents);
+        field.ty = Type::Verbatim(ident.to_token_stream());
+        generics.push(GenericParam::Type(syn::TypeParam {
+            ident,
+            attrs: vec![],
+            colon_token: (!bounds.is_empty()).then(syn::token::Colon::default),
+            bounds,
+            eq_token: None,
+            default: None,
+        }));
+    }
+
+    out.extend(template::item_tokens(template_struct.clone(), is_union));
+    let mut variants = vec![];
+    for derived in &attribute_inputs.derived_structs {
+        let variant = match template::resolve(&fields, derived, &attribute_inputs.settings, false) {
+            Ok(v) => v,
+            Err(e) => {
+                custom_compiler_error_msg!(out, "{}", e);
+                return out.into();
+            }
+        };
+        // this has to match the order of the generics
+        let types: Vec<_> = fields
+            .iter()
+            .zip(&variant.types)
+            .filter(|(field, _)| field.either.is_some())
+            .map(|(_, t)| t)
+            .collect();
+
+        let generic_name = template_struct.ident.clone();
+        let generic_names: Vec<_> = initial_generics
+            .type_params()
+            .map(|x| x.ident.clone())
+            .collect();
+        let comma = if generic_names.is_empty() {
+            None
+        } else {
+            Some(syn::token::Comma::default())
+        };
+        let x = syn::ItemType {
+            type_token: syn::token::Type::default(),
+            semi_token: syn::token::Semi::default(),
+            eq_token: syn::token::Eq::default(),
+            attrs: vec![],
+            ident: derived.name.clone(),
+            vis: derived.vis.clone(),
+            ty: std::boxed::Box::new(Type::Verbatim(quote! {
+                #generic_name<#(#generic_names),* #comma #(#types),*>
+            })),
+            generics: helper::without_bounds(&initial_generics),
+        };
+
+        out.extend(docs::gen_variant_docs(
+            &template_struct.ident,
+            &fields,
+            &variant,
+            &attribute_inputs.settings,
+        ));
+        out.extend::<proc_macro2::TokenStream>(x.into_token_stream());
+        variants.push(variant);
+    }
+
+    if report::is_enabled(&attribute_inputs.settings) {
+        let template_args: Vec<_> = initial_generics
+            .type_params()
+            .map(|x| x.ident.to_string())
+            .collect();
+        let report = report::gen_report(
+            &template_struct.ident,
+            &fields,
+            &variants,
+            &attribute_inputs.settings,
+            |variant| {
+                let types = fields
+                    .iter()
+                    .zip(&variant.types)
+                    .filter(|(field, _)| field.either.is_some())
+                    .map(|(_, t)| helper::pretty(t));
+                format!(
+                    "{} = {}<{}>",
+                    variant.derived.name,
+                    template_struct.ident,
+                    template_args
+                        .iter()
+                        .cloned()
+                        .chain(types)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            },
+        );
+        if let Err(e) = report::write_report(&template_struct.ident, &report) {
+            custom_compiler_error_msg!(out, "{}", e);
+        }
+    }
+
+    if attribute_inputs.settings.assert_layout {
+        match assertions::gen_layout_assertions(
+            &template_struct.attrs,
+            &initial_generics,
+            &fields,
+            &template::distinct(&variants, &attribute_inputs.settings),
+            &attribute_inputs.settings,
+        ) {
+            Ok(v) => out.extend(v),
+            Err(e) => {
+                custom_compiler_error_msg!(out, "{}", e);
+            }
+        }
+    }
+    out.extend(assertions::gen_impl_assertions(
+        &fields,
+        &template::distinct(&variants, &attribute_inputs.settings),
+        &initial_generics,
+        &attribute_inputs.settings,
+    ));
+    if attribute_inputs.settings.generate_views {
+        match views::gen_views(
+            &template_struct.attrs,
+            &fields,
+            &template::distinct(&variants, &attribute_inputs.settings),
+            &initial_generics,
+            &attribute_inputs.settings,
+        ) {
+            Ok(v) => out.extend(v),
+            Err(e) => {
+                custom_compiler_error_msg!(out, "{}", e);
+            }
+        }
+    }
+    if is_union {
+        out.extend(assertions::gen_union_assertions(
+            &fields,
+            &template::distinct(&variants, &attribute_inputs.settings),
+            &initial_generics,
+            &attribute_inputs.settings,
+        ));
+        return out.into();
+    }
+    out.extend(assertions::gen_bound_assertions(
+        &fields,
+        &template::distinct(&variants, &attribute_inputs.settings),
+        &initial_generics,
+    ));
+    if attribute_inputs.settings.generate_traits {
+        out.extend(introspection::gen_generic_traits(
+            &template_struct,
+            &initial_generics,
+            &fields,
+            &template::distinct(&variants, &attribute_inputs.settings),
+        ));
+    }
+    if let Err(e) = constructors::check_shared_values(&variants) {
+        custom_compiler_error_msg!(out, "{}", e);
+        return out.into();
+    }
+    out.extend(constructors::gen_constructors(
+        &fields,
+        &template::distinct(&variants, &attribute_inputs.settings),
+        &initial_generics,
+        &attribute_inputs.settings,
+        matches!(template_struct.fields, syn::Fields::Unnamed(_)),
+        &template_struct.attrs,
+    ));
+    if attribute_inputs.settings.generate_transitions {
+        out.extend(transitions::gen_generic_transitions(
+            &template_struct,
+            &fields,
+            &mut taken_idents,
+        ));
+    }
+    if attribute_inputs.settings.generate_maps {
+        out.extend(maps::gen_maps(&template_struct, &fields, &mut taken_idents));
+    }
+    if attribute_inputs.settings.generate_accessors {
+        out.extend(accessors::gen_generic_accessors(&template_struct, &fields));
+    }
+
+    out.into()
+}
+
+fn gen_structs(
+    mut out: proc_macro2::TokenStream,
+    mut template_struct: syn::ItemStruct,
+    attribute_inputs: minor_parsing::AttrInputs,
+    is_tuple: bool,
+    is_union: bool,
+) -> TokenStream {
+    let settings = &attribute_inputs.settings;
+    if settings.generate_maps {
+        custom_compiler_error_msg!(out, "GenMaps requires GenStructs to be false");
+    }
+    if settings.delete_template && settings.changes_template() {
+        custom_compiler_error_msg!(
+            out,
+            "HideTemplate, TemplateVis and RenameTemplate can't be used with DeleteTemplate, as the template isn't kept"
+        );
+    }
+    let fields = match template::parse_fields(&mut template_struct.fields) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    /* Parsing Template's Types */
+    let mut keep_template = !settings.delete_template;
+    for (field, template_field) in template_struct.fields.iter_mut().zip(&fields) {
+        if let Some(either) = &template_field.either {
+            if !either.has_default_type() && keep_template {
+                keep_template = false;
+                custom_compiler_error_msg!(
+                    out,
+                    "The template can't be kept, as the default of field \"{}\" is a bound. Set DeleteTemplate to true, or mark a type as default",
+                    template_field.key
+                );
+            }
+            field.ty.clone_from(either.default_type());
+        }
+    }
+
+    /* Spitting Tokens Out */
+    let mut variants = vec![];
+    for derived in &attribute_inputs.derived_structs {
+        let variant = match template::resolve(&fields, derived, settings, true) {
+            Ok(v) => v,
+            Err(e) => {
+                custom_compiler_error_msg!(out, "{}", e);
+                continue;
+            }
+        };
+
+        // the template's docs are kept unless the derived struct has its own
+        for attr in &template_struct.attrs {
+            if derived.docs.is_empty() || !docs::is_doc(attr) {
+                attr.to_tokens(&mut out);
+            }
+        }
+        out.extend(docs::gen_variant_docs(
+            &template_struct.ident,
+            &fields,
+            &variant,
+            settings,
+        ));
+        derived.vis.to_tokens(&mut out);
+        match is_union {
+            true => syn::token::Union(template_struct.struct_token.span).to_tokens(&mut out),
+            false => template_struct.struct_token.to_tokens(&mut out),
+        }
+        derived.name.to_tokens(&mut out);
+        template_struct.generics.to_tokens(&mut out);
+        if !is_tuple {
+            template_struct.generics.where_clause.to_tokens(&mut out);
+        }
+
+        let mut fields_token_stream = proc_macro2::TokenStream::new();
+        for (field_number, (field, field_type)) in template_struct
+            .fields
+            .iter()
+            .zip(&variant.types)
+            .enumerate()
+        {
+            if variant.is_omitted(field_number, settings) {
+                continue;
+            }
+            for attr in &field.attrs {
+                attr.to_tokens(&mut fields_token_stream);
+            }
+            // `Absent` fields which aren't omitted are left out of serde instead
+            if template::is_absent(field_type) && template::derives_serde(&template_struct.attrs) {
+                quote! { #[serde(skip)] }.to_tokens(&mut fields_token_stream);
+            }
+            field.vis.to_tokens(&mut fields_token_stream);
+            // field.mutability.to_tokens(&mut fields_token_stream);
+            if !is_tuple {
+                field.ident.to_tokens(&mut fields_token_stream);
+                field.colon_token.to_tokens(&mut fields_token_stream);
+            }
+            field_type.to_tokens(&mut fields_token_stream);
+            Comma::default().to_tokens(&mut fields_token_stream);
+        }
+
+        match is_tuple {
+            true => {
+                syn::token::Paren::default()
+                    .surround(&mut out, |out| fields_token_stream.to_tokens(out));
+                template_struct.generics.where_clause.to_tokens(&mut out);
+                syn::token::Semi::default().to_tokens(&mut out);
+            }
+            false => syn::token::Brace::default()
+                .surround(&mut out, |out| fields_token_stream.to_tokens(out)),
+        }
+        variants.push(variant);
+    }
+
+    if report::is_enabled(settings) {
+        let kind = match is_union {
+            true => "union",
+            false => "struct",
+        };
+        let report = report::gen_report(
+            &template_struct.ident,
+            &fields,
+            &variants,
+            settings,
+            |variant| format!("{} {}", kind, variant.derived.name),
+        );
+        if let Err(e) = report::write_report(&template_struct.ident, &report) {
+            custom_compiler_error_msg!(out, "{}", e);
+        }
+    }
+
+    if settings.assert_layout {
+        match assertions::gen_layout_assertions(
+            &template_struct.attrs,
+            &template_struct.generics,
+            &fields,
+            &variants.iter().collect::<Vec<_>>(),
+            settings,
+        ) {
+            Ok(v) => out.extend(v),
+            Err(e) => {
+                custom_compiler_error_msg!(out, "{}", e);
+            }
+        }
+    }
+    out.extend(assertions::gen_impl_assertions(
+        &fields,
+        &variants.iter().collect::<Vec<_>>(),
+        &template_struct.generics,
+        settings,
+    ));
+    if settings.generate_views {
+        match views::gen_views(
+            &template_struct.attrs,
+            &fields,
+            &variants.iter().collect::<Vec<_>>(),
+            &template_struct.generics,
+            settings,
+        ) {
+            Ok(v) => out.extend(v),
+            Err(e) => {
+                custom_compiler_error_msg!(out, "{}", e);
+            }
+        }
+    }
+    out.extend(assertions::gen_bound_assertions(
+        &fields,
+        &variants.iter().collect::<Vec<_>>(),
+        &template_struct.generics,
+    ));
+    match is_union {
+        true => out.extend(assertions::gen_union_assertions(
+            &fields,
+            &variants.iter().collect::<Vec<_>>(),
+            &template_struct.generics,
+            settings,
+        )),
+        false => {
+            if settings.generate_traits {
+                out.extend(introspection::gen_struct_traits(
+                    settings
+                        .rename_template
+                        .as_ref()
+                        .unwrap_or(&template_struct.ident),
+                    &fields,
+                    &variants.iter().collect::<Vec<_>>(),
+                    &template_struct.generics,
+                    settings,
+                    keep_template,
+                    assertions::has_repr(&template_struct.attrs, "packed"),
+                ));
+            }
+            out.extend(constructors::gen_constructors(
+                &fields,
+                &variants.iter().collect::<Vec<_>>(),
+                &template_struct.generics,
+                settings,
+                is_tuple,
+                &template_struct.attrs,
+            ))
+        }
+    }
+    if settings.generate_accessors && !is_union {
+        out.extend(accessors::gen_struct_accessors(
+            &template_struct.vis,
+            settings
+                .rename_template
+                .as_ref()
+                .unwrap_or(&template_struct.ident),
+            &fields,
+            &variants.iter().collect::<Vec<_>>(),
+            &template_struct.generics,
+            settings,
+        ));
+    }
+    if settings.generate_transitions {
+        let mut taken_idents = std::collections::HashSet::new();
+        helper::collect_idents(template_struct.to_token_stream(), &mut taken_idents);
+        out.extend(transitions::gen_struct_transitions(
+            &template_struct.ident,
+            &fields,
+            &variants.iter().collect::<Vec<_>>(),
+            &template_struct.generics,
+            settings,
+            is_tuple,
+            &mut taken_idents,
+        ));
+    }
+
+    if keep_template {
+        if settings.delete_empty_tuple_fields || settings.omit_absent_fields {
+            let cb = |t: &syn::Type| !template::is_omittable(t, settings);
+            match template_struct.fields {
+                syn::Fields::Unit => unsafe { unreachable_unchecked() },
+                syn::Fields::Named(_) => {
+                    template_struct.fields = syn::Fields::Named(syn::FieldsNamed {
+                        brace_token: syn::token::Brace::default(),
+                        named: template_struct
+                            .fields
+                            .into_iter()
+                            .filter(|f| cb(&f.ty))
+                            .collect(),
+                    })
+                }
+                syn::Fields::Unnamed(_) => {
+                    template_struct.fields = syn::Fields::Unnamed(syn::FieldsUnnamed {
+                        paren_token: syn::token::Paren::default(),
+                        unnamed: template_struct
+                            .fields
+                            .into_iter()
+                            .filter(|f| cb(&f.ty))
+                            .collect(),
+                    })
+                }
+            }
+        }
+
+        if let Some(name) = &settings.rename_template {
+            template_struct.ident = name.clone();
+        }
+        if let Some(vis) = &settings.template_vis {
+            template_struct.vis = vis.clone();
+        }
+        if settings.hide_template {
+            template_struct
+                .attrs
+                .push(syn::parse_quote! { #[doc(hidden)] });
+        }
+        out.extend(template::item_tokens(template_struct, is_union));
+    }
+
+    out.into()
+}
+
+/// An alternative to [`macro@make_template`] for tooling that handles derives better
+///
+/// The input of [`macro@make_template`] goes in a `#[variants()]` attribute, and
+/// the fields using [`macro@either`] are marked with `#[either()]` instead, listing the
+/// possible types separated by commas:
+/// ```
+/// # use either_field::EitherVariants;
+/// # #[derive(Debug)]
+/// # struct PlayerData;
+/// #[derive(Debug, EitherVariants)]
+/// #[variants(
+///     GenStructs: true;
+///     ScoreWithPlayer: [player: PlayerData]
+/// )]
+/// struct Score {
+///     #[either(i32, PlayerData)]
+///     player: i32,
+///     value: i32
+/// }
+/// ```
+/// Derives don't see the struct's `#[derive()]` attributes, so attributes meant for the
+/// generated structs go in `#[variant_attr()]`, i.e. `#[variant_attr(derive(Debug))]`.
+///
+/// Since a derive can't change the struct it's on, the struct is never deleted, and
+/// without `GenStructs` every `#[either()]` field has to be declared with one of the
+/// struct's type parameters, which the type declarations fill in:
+/// ```
+/// # use either_field::EitherVariants;
+/// # struct PlayerData;
+/// #[derive(EitherVariants)]
+/// #[variants(ScoreWithPlayer: [player: PlayerData])]
+/// struct Score<P> {
+///     #[either(i32, PlayerData)]
+///     player: P,
+///     value: i32
+/// }
+/// // type ScoreWithPlayer = Score<PlayerData>;
+/// ```
+#[proc_macro_derive(EitherVariants, attributes(variants, variant_attr, either, default))]
+pub fn derive_either_variants(items: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let mut out = proc_macro2::TokenStream::new();
+    let (template_struct, is_union) =
+        match template::from_item(parse_macro_input!(items as syn::Item)) {
+            Ok(v) => v,
+            Err(e) => return e.into_compile_error().into(),
+        };
+    let (mut template_struct, attr, declared_types) = match derive::to_template(template_struct) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let mut attribute_inputs = match syn::parse2::<minor_parsing::AttrInputs>(attr.clone()) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    if let Err(e) = naming::name_derived_structs(
+        &mut attribute_inputs,
+        &template_struct.ident,
+        &template_struct.fields,
+    ) {
+        return e.into_compile_error().into();
+    }
+    if attribute_inputs.settings.delete_template {
+        custom_compiler_error_msg!(
+            out,
+            "DeleteTemplate can't be used with #[derive(EitherVariants)]"
+        );
+        return out.into();
+    }
+    if attribute_inputs.settings.changes_template() {
+        custom_compiler_error_msg!(
+            out,
+            "HideTemplate, TemplateVis and RenameTemplate can't be used with #[derive(EitherVariants)], as the item stays as it is"
+        );
+        return out.into();
+    }
+    out.extend(descriptor::gen_descriptor(
+        &template_struct.ident,
+        attr,
+        template::item_tokens(template_struct.clone(), is_union),
+        attribute_inputs.settings.export_template,
+    ));
+    if is_union {
+        check_union(&mut out, &template_struct, &attribute_inputs);
+    }
+
+    // the item itself stays where it is, the module reaches it with `use super::*`
+    let module = module::Module::take(&mut template_struct, &mut attribute_inputs, false);
+    let mut parent = proc_macro2::TokenStream::new();
+    if module.is_some() {
+        std::mem::swap(&mut parent, &mut out);
+    }
+
+    let generated = match (
+        &template_struct.fields,
+        attribute_inputs.settings.generate_structs,
+    ) {
+        (syn::Fields::Unit, _) => {
+            custom_compiler_error_msg!(out, "Unit structs have no fields to do anything about.");
+            out.into()
+        }
+        (_, false) => {
+            let fields = match template::parse_fields(&mut template_struct.fields) {
+                Ok(v) => v,
+                Err(e) => return e.into_compile_error().into(),
+            };
+            match derive::gen_derived_types(
+                &template_struct,
+                &fields,
+                &declared_types,
+                &attribute_inputs,
+            ) {
+                Ok(v) => out.extend(v),
+                Err(e) => {
+                    custom_compiler_error_msg!(out, "{}", e);
+                }
+            }
+            out.into()
+        }
+        (fields, true) => {
+            let is_tuple = matches!(fields, syn::Fields::Unnamed(_));
+            attribute_inputs.settings.delete_template = true;
+            gen_structs(out, template_struct, attribute_inputs, is_tuple, is_union)
+        }
+    };
+    match module {
+        Some(module) => {
+            parent.extend(module.wrap(generated.into()));
+            parent.into()
+        }
+        None => generated,
+    }
+}
+
+/// Copies an impl block to the derived structs of a template
+///
+/// Placed on an impl block written for the template, it emits the same impl block
+/// for every derived struct, or only for the ones listed:
+/// ```
+/// # use either_field::{either, make_template, template_impl};
+/// # struct PlayerData;
+/// # #[make_template(
+/// #     ScoreWithId: [player: i32],
+/// #     ScoreWithPlayer: [player: PlayerData]
+/// # )]
+/// # struct Score {
+/// #     player: either!(i32 | PlayerData),
+/// # }
+/// #[template_impl(Score)]
+/// impl Score {
+///     fn player(&self) -> &either!(player) {
+///         &self.player
+///     }
+/// }
+///
+/// #[template_impl(Score: [ScoreWithPlayer])]
+/// impl Score { /* ... */ }
+/// ```
+/// Inside the impl block, `either!(field_name)` (or `either!(0)` for tuple structs)
+/// stands for the type the field has in each derived struct.
+///
+/// The impl block has to come after the template, in the same module or a child one.
+#[proc_macro_attribute]
+pub fn template_impl(
+    attr: proc_macro::TokenStream,
+    items: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attr_clone = attr.clone();
+    let target = parse_macro_input!(attr_clone as impls::ImplTarget);
+    impls::forward_to_descriptor(
+        &target.template,
+        impls::callback_path("__template_impl"),
+        attr.into(),
+        items.into(),
+    )
+    .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __template_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let described =
+        parse_macro_input!(input as descriptor::Described<impls::ImplPayload<impls::ImplTarget>>);
+    match impls::template_impl(described) {
+        Ok(v) => v.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Implements an impl block only for the derived structs with specific field types
+///
+/// The field types are listed the same way as in a derived struct of
+/// [`macro@make_template`], and the impl block is emitted for every derived
+/// struct that has all of them:
+/// ```
+/// # use either_field::{either, make_template, variant_impl};
+/// # struct PlayerData {
+/// #     player_name: String,
+/// # }
+/// # #[make_template(
+/// #     ScoreWithId: [player: i32],
+/// #     ScoreWithPlayer: [player: PlayerData]
+/// # )]
+/// # struct Score {
+/// #     player: either!(i32 | PlayerData),
+/// # }
+/// #[variant_impl(Score: [player: PlayerData])]
+/// impl Score {
+///     fn player_name(&self) -> &str {
+///         &self.player.player_name
+///     }
+/// }
+/// ```
+/// Just like [`macro@template_impl`], `either!(field_name)` can be used within the
+/// impl block, which has to come after the template.
+#[proc_macro_attribute]
+pub fn variant_impl(
+    attr: proc_macro::TokenStream,
+    items: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let attr_clone = attr.clone();
+    let predicate = parse_macro_input!(attr_clone as minor_parsing::Derived);
+    if predicate.unnamed {
+        return syn::Error::new(predicate.name.span(), "Expected the name of the template")
+            .into_compile_error()
+            .into();
+    }
+    impls::forward_to_descriptor(
+        &predicate.name,
+        impls::callback_path("__variant_impl"),
+        attr.into(),
+        items.into(),
+    )
+    .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __variant_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let described = parse_macro_input!(
+        input as descriptor::Described<impls::ImplPayload<minor_parsing::Derived>>
+    );
+    match impls::variant_impl(described) {
+        Ok(v) => v.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Adds derived structs to a template exported by another crate
+///
+/// The template has to be defined with `ExportTemplate: true`, and is referred to by
+/// its path starting with the name of the crate defining it. What follows `=>` is the
+/// same as the input of [`macro@make_template`]:
+/// ```ignore
+/// either_field::extend_template! {
+///     shared::scores::Score => ScoreWithName: [player: String]
+/// }
+/// ```
+/// The derived structs are generated the same way as the template's own,
+/// so the only setting that can be used is `RequireAllFields`.
+#[proc_macro]
+pub fn extend_template(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as export::ExtendInput);
+    let descriptor = match export::descriptor_path(&input.path) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let callback = impls::callback_path("__extend_template");
+    let path = &input.path;
+    let attr = &input.attr;
+    quote! {
+        #descriptor! { [#callback] (#path) #attr }
+    }
+    .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __extend_template(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let described = parse_macro_input!(input as descriptor::Described<export::ExtendPayload>);
+    let mut out = proc_macro2::TokenStream::new();
+    let export::ExtendPayload {
+        path,
+        attribute_inputs: extension,
+    } = described.payload;
+    let settings = match export::extended_settings(
+        &described.attribute_inputs.settings,
+        &extension.settings,
+    ) {
+        Ok(v) => v,
+        Err(e) => {
+            custom_compiler_error_msg!(out, "{}", e);
+            return out.into();
+        }
+    };
+    let mut attribute_inputs = minor_parsing::AttrInputs {
+        settings,
+        has_settings: true,
+        derived_structs: extension.derived_structs,
+    };
+
+    let mut template_struct = described.template;
+    if let Err(e) = naming::name_derived_structs(
+        &mut attribute_inputs,
+        &template_struct.ident,
+        &template_struct.fields,
+    ) {
+        return e.into_compile_error().into();
+    }
+    match (
+        &template_struct.fields,
+        attribute_inputs.settings.generate_structs,
+    ) {
+        (_, false) => {
+            let fields = match template::parse_fields(&mut template_struct.fields) {
+                Ok(v) => v,
+                Err(e) => return e.into_compile_error().into(),
+            };
+            match export::gen_extended_types(&path, &template_struct, &fields, &attribute_inputs) {
+                Ok(v) => out.extend(v),
+                Err(e) => {
+                    custom_compiler_error_msg!(out, "{}", e);
+                }
+            }
+            out.into()
+        }
+        (syn::Fields::Unnamed(_), true) => gen_structs(
+            out,
+            template_struct,
+            attribute_inputs,
+            true,
+            described.is_union,
+        ),
+        (_, true) => gen_structs(
+            out,
+            template_struct,
+            attribute_inputs,
+            false,
+            described.is_union,
+        ),
+    }
+}
+
+/// Compiler Magic
+///
+/// this makes an export for LSPs and the compiler to not freak out but allows the syntax
+/// for [`macro@make_template`] to be syntactically valid according to the compiler. This
+/// relies on the fact that the [`macro@make_template`] macro compiles before this macro.
+#[proc_macro]
+pub fn either(_: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    TokenStream::new()
+}
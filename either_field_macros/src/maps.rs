@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Ident;
+
+use crate::{
+    helper,
+    template::TemplateField,
+    transitions::{access, construct, generic_args},
+};
+
+// In type declaration mode, `map_field(f)` turns the template into the instance of
+// it where the field's type is whatever `f` returns, keeping the other fields.
+//
+// `try_map_field(f)` does the same with a fallible `f`, and both
+// have an `_async` counterpart taking a closure returning a future
+//
+// `template` is the template after its fields have been turned into generics
+pub(crate) fn gen_maps(
+    template: &syn::ItemStruct,
+    fields: &[TemplateField],
+    taken_idents: &mut HashSet<String>,
+) -> TokenStream {
+    let name = &template.ident;
+    let vis = &template.vis;
+    let is_tuple = matches!(template.fields, syn::Fields::Unnamed(_));
+    let new_generic = helper::generate_generic_name("mapped", taken_idents);
+    let error_generic = helper::generate_generic_name("error", taken_idents);
+    let future_generic = helper::generate_generic_name("future", taken_idents);
+    let (impl_generics, ty_generics, where_clause) = template.generics.split_for_impl();
+
+    let mut methods = vec![];
+    for (field_number, (field, template_field)) in template.fields.iter().zip(fields).enumerate() {
+        if template_field.either.is_none() {
+            continue;
+        }
+        let syn::Type::Verbatim(generic) = &field.ty else {
+            continue;
+        };
+        let Ok(generic) = syn::parse2::<Ident>(generic.clone()) else {
+            continue;
+        };
+
+        let output_args = generic_args(&template.generics, Some((&generic, &new_generic)));
+        let values: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .map(|(other_number, other)| match other_number == field_number {
+                true => (other, quote! { mapped }),
+                false => (other, access(other, other_number)),
+            })
+            .collect();
+        let body = construct(&quote! { #name }, &values, is_tuple);
+        let accessed = access(template_field, field_number);
+        let field_name = match &template_field.field.ident {
+            Some(ident) => syn::ext::IdentExt::unraw(ident).to_string(),
+            None => template_field.key.clone(),
+        };
+        let map = format_ident!("map_{}", field_name);
+        let try_map = format_ident!("try_map_{}", field_name);
+        let map_async = format_ident!("map_{}_async", field_name);
+        let try_map_async = format_ident!("try_map_{}_async", field_name);
+        let bounds = helper::bounds_of(&template.generics, &generic);
+        let [map_doc, try_map_doc, map_async_doc, try_map_async_doc] = [
+            format!("Changes `{field_name}` with `f`, and its type with it"),
+            format!("Changes `{field_name}` with `f`, unless it fails"),
+            format!("Changes `{field_name}` with the future `f` returns"),
+            format!("Changes `{field_name}` with the future `f` returns, unless it fails"),
+        ];
+        methods.push(quote! {
+            #[doc = #map_doc]
+            #vis fn #map<#new_generic: #bounds>(
+                self,
+                f: impl ::core::ops::FnOnce(#generic) -> #new_generic,
+            ) -> #name #output_args {
+                let mapped = f(#accessed);
+                #body
+            }
+
+            #[doc = #try_map_doc]
+            #vis fn #try_map<#new_generic: #bounds, #error_generic>(
+                self,
+                f: impl ::core::ops::FnOnce(#generic) -> ::core::result::Result<#new_generic, #error_generic>,
+            ) -> ::core::result::Result<#name #output_args, #error_generic> {
+                let mapped = f(#accessed)?;
+                ::core::result::Result::Ok(#body)
+            }
+
+            #[doc = #map_async_doc]
+            #vis async fn #map_async<#new_generic: #bounds, #future_generic>(
+                self,
+                f: impl ::core::ops::FnOnce(#generic) -> #future_generic,
+            ) -> #name #output_args
+            where
+                #future_generic: ::core::future::Future<Output = #new_generic>,
+            {
+                let mapped = f(#accessed).await;
+                #body
+            }
+
+            #[doc = #try_map_async_doc]
+            #vis async fn #try_map_async<#new_generic: #bounds, #error_generic, #future_generic>(
+                self,
+                f: impl ::core::ops::FnOnce(#generic) -> #future_generic,
+            ) -> ::core::result::Result<#name #output_args, #error_generic>
+            where
+                #future_generic: ::core::future::Future<
+                    Output = ::core::result::Result<#new_generic, #error_generic>,
+                >,
+            {
+                let mapped = f(#accessed).await?;
+                ::core::result::Result::Ok(#body)
+            }
+        });
+    }
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use proc_macro2::{Literal, Span, TokenTree};
+use syn::{
+    Expr, Ident, LitBool, Token, Type, Visibility, bracketed, ext::IdentExt, parse::Parse,
+    punctuated::Punctuated,
+};
+
+macro_rules! syn_error {
+    ($message: literal) => {
+        syn::Result::Err(syn::Error::new(Span::call_site(), $message))
+    };
+}
+
+// This is the struct that handles
+// parsing all the settings
+//
+// SettingName: Value, ...;
+#[derive(Debug, Default)]
+pub(crate) struct Settings {
+    pub generate_structs: bool,
+    pub delete_template: bool,
+    pub delete_empty_tuple_fields: bool,
+    pub omit_absent_fields: bool,
+    pub require_all_fields: bool,
+    pub generate_transitions: bool,
+    pub generate_maps: bool,
+    pub assert_layout: bool,
+    pub generate_views: bool,
+    pub generate_accessors: bool,
+    pub generate_traits: bool,
+    pub export_template: bool,
+    pub report: bool,
+    pub naming: Option<syn::LitStr>,
+    pub module: Option<(Visibility, Ident)>,
+    pub reexport: Vec<(Visibility, Ident)>,
+    pub hide_template: bool,
+    pub template_vis: Option<Visibility>,
+    pub rename_template: Option<Ident>,
+    pub assert_impl: Vec<syn::TypeParamBound>,
+}
+impl Settings {
+    // Whether the template kept with GenStructs is emitted differently
+    pub fn changes_template(&self) -> bool {
+        self.hide_template || self.template_vis.is_some() || self.rename_template.is_some()
+    }
+}
+// impl Default for Settings {
+//     fn default() -> Self {
+//         Self {
+//             generate_structs: false,
+//             delete_template: false,
+//             delete_empty_tuple_fields: false,
+//         }
+//     }
+// }
+
+// This is the struct that handles
+// parsing all the derived structs
+//
+// SettingName: Value, ...;
+pub(crate) struct AttrInputs {
+    pub settings: Settings,
+    pub has_settings: bool,
+    pub derived_structs: Vec<Derived>,
+}
+impl Parse for AttrInputs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut settings = Settings::default();
+        // the settings are whatever comes before a `;`
+        let mut settings_fork_cursor = input.fork().cursor();
+        let mut has_settings = false;
+
+        while let Some((tt, next)) = settings_fork_cursor.token_tree() {
+            match &tt {
+                TokenTree::Punct(punct) if punct.as_char() == ';' => {
+                    has_settings = true;
+                    break;
+                }
+                _ => settings_fork_cursor = next,
+            }
+        }
+
+        if has_settings {
+            loop {
+                let ident = input.parse::<Ident>()?;
+                let _ = input.parse::<Token![:]>()?;
+
+                match ident.to_string().as_str() {
+                    "AssertImpl" => {
+                        let list;
+                        bracketed!(list in input);
+                        let bounds: Punctuated<syn::TypeParamBound, Token![,]> =
+                            Punctuated::parse_terminated(&list)?;
+                        settings.assert_impl = bounds.into_iter().collect();
+                    }
+                    "Naming" => settings.naming = Some(input.parse()?),
+                    "TemplateVis" => settings.template_vis = Some(input.parse()?),
+                    "RenameTemplate" => settings.rename_template = Some(input.parse()?),
+                    "Module" => settings.module = Some((input.parse()?, input.parse()?)),
+                    "Reexport" => {
+                        let list;
+                        bracketed!(list in input);
+                        let items =
+                            Punctuated::<_, Token![,]>::parse_terminated_with(&list, |input| {
+                                Ok((input.parse::<Visibility>()?, input.parse::<Ident>()?))
+                            })?;
+                        settings.reexport = items.into_iter().collect();
+                    }
+                    name => {
+                        if !input.peek(LitBool) {
+                            return syn_error!("Invalid setting value");
+                        }
+                        let value = input.parse::<LitBool>()?.value;
+                        match name {
+                            "GenStructs" => settings.generate_structs = value,
+                            "DeleteTemplate" => settings.delete_template = value,
+                            "OmitEmptyTupleFields" => settings.delete_empty_tuple_fields = value,
+                            "OmitAbsentFields" => settings.omit_absent_fields = value,
+                            "RequireAllFields" => settings.require_all_fields = value,
+                            "GenTransitions" => settings.generate_transitions = value,
+                            "GenMaps" => settings.generate_maps = value,
+                            "AssertLayout" => settings.assert_layout = value,
+                            "GenViews" => settings.generate_views = value,
+                            "GenAccessors" => settings.generate_accessors = value,
+                            "GenTraits" => settings.generate_traits = value,
+                            "ExportTemplate" => settings.export_template = value,
+                            "Report" => settings.report = value,
+                            "HideTemplate" => settings.hide_template = value,
+                            _ => (),
+                        }
+                    }
+                }
+
+                let lookahead = input.lookahead1();
+                if lookahead.peek(Token![;]) {
+                    let _ = input.parse::<Token![;]>();
+                    break;
+                } else if lookahead.peek(Token![,]) {
+                    let _ = input.parse::<Token![,]>();
+                } else {
+                    return syn_error!("Invalid character");
+                }
+            }
+        }
+
+        if settings.module.is_none() && !settings.reexport.is_empty() {
+            return syn_error!("Reexport requires a Module to re-export from");
+        }
+
+        let parsed: Punctuated<Derived, Token![,]> = Punctuated::parse_terminated(input)?;
+
+        Ok(Self {
+            settings,
+            has_settings,
+            derived_structs: parsed.into_iter().collect(),
+        })
+    }
+}
+
+// This is the struct that handles parsing the
+// syntax for specifying the derived structs
+//
+// VIS is the visibility
+//
+// /// DOCS
+// VIS struct_name: FieldDescriptor
+//
+// where the name can be `_` to have it named by the Naming pattern
+pub(crate) struct Derived {
+    pub docs: Vec<syn::Attribute>,
+    pub vis: Visibility,
+    pub name: Ident,
+    pub unnamed: bool,
+    pub fields: std::collections::HashMap<String, Type>,
+    pub values: std::collections::HashMap<String, Expr>,
+}
+impl Parse for Derived {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let docs = input.call(syn::Attribute::parse_outer)?;
+        if let Some(attr) = docs.iter().find(|attr| !crate::docs::is_doc(attr)) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Only doc comments can be written on derived structs",
+            ));
+        }
+        let vis = input.parse::<Visibility>()?;
+        let unnamed = input.peek(Token![_]);
+        let name = match unnamed {
+            true => Ident::new("_", input.parse::<Token![_]>()?.span),
+            false => input.parse::<Ident>()?,
+        };
+        let _ = input.parse::<Token![:]>()?;
+        let field_list;
+        bracketed!(field_list in input);
+
+        let mut fields = std::collections::HashMap::new();
+        let mut values = std::collections::HashMap::new();
+        for (ident_number, field) in
+            (<Punctuated<FieldDescriptor, Token![,]>>::parse_separated_nonempty(&field_list)?)
+                .into_iter()
+                .enumerate()
+        {
+            let ident = match field.ident {
+                Some(ident) => ident,
+                None => ident_number.to_string(),
+            };
+            if let Some(value) = field.value {
+                values.insert(ident.clone(), value);
+            }
+            fields.insert(ident, field.field_type);
+        }
+
+        Ok(Self {
+            docs,
+            name,
+            unnamed,
+            fields,
+            values,
+            vis,
+        })
+    }
+}
+
+// This is the struct that handles parsing the syntax
+// for specifying field types in normal structs
+//
+// field_name: type = default_value, ...
+//
+// where the default value is optional
+pub(crate) struct FieldDescriptor {
+    ident: Option<String>,
+    field_type: Type,
+    value: Option<Expr>,
+}
+impl Parse for FieldDescriptor {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        // `::` would also match a single colon, but it's part of a path type
+        let has_key = input.peek2(Token![:]) && !input.peek2(Token![::]);
+        let ident = match (has_key, input.peek(Ident)) {
+            (true, true) => {
+                let out = input.parse::<Ident>()?;
+                let _ = input.parse::<Token![:]>()?;
+                Some(out.unraw().to_string())
+            }
+            (true, false) => {
+                let x = input.parse::<Literal>()?;
+                let out = x.to_string();
+                if out.chars().any(|c| !c.is_numeric()) {
+                    return syn_error!("Literal is not number");
+                }
+                let _ = input.parse::<Token![:]>()?;
+                Some(out)
+            }
+            (false, _) => None,
+        };
+
+        let field_type = input.parse::<Type>()?;
+        let value = match input.peek(Token![=]) {
+            true => {
+                let _ = input.parse::<Token![=]>()?;
+                Some(input.parse::<Expr>()?)
+            }
+            false => None,
+        };
+        Ok(Self {
+            ident,
+            field_type,
+            value,
+        })
+    }
+}
+
+// This is the struct that handles parsing the either!() macro's contents
+//
+// (type | default type | ... )
+//
+// at most one of the types can be marked with `default`,
+// otherwise the first one is the default
+//
+// at most one of the types can be a bound (`impl Trait`),
+// which any type satisfying it can be picked for
+pub(crate) struct EitherMacro {
+    pub types: Vec<Type>,
+    pub default: usize,
+}
+impl EitherMacro {
+    pub fn default_type(&self) -> &Type {
+        &self.types[self.default]
+    }
+    // A bound can't be used as a type, so fields
+    // defaulting to it have to be given one
+    pub fn has_default_type(&self) -> bool {
+        !matches!(self.default_type(), Type::ImplTrait(_))
+    }
+    pub fn bound(&self) -> Option<&syn::TypeImplTrait> {
+        self.types.iter().find_map(|t| match t {
+            Type::ImplTrait(bound) => Some(bound),
+            _ => None,
+        })
+    }
+    // Whether the field can only be given types satisfying the bound
+    pub fn is_only_bound(&self) -> bool {
+        self.types.len() == 1 && self.bound().is_some()
+    }
+}
+impl Parse for EitherMacro {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut types = vec![];
+        let mut default = None;
+        loop {
+            let fork = input.fork();
+            if let Ok(keyword) = fork.parse::<Ident>()
+                && keyword == "default"
+                && !fork.is_empty()
+                && !fork.peek(Token![|])
+                // `default::Type` is a path, not the marker
+                && !fork.peek(Token![::])
+            {
+                input.parse::<Ident>()?;
+                if default.is_some() {
+                    return Err(syn::Error::new(
+                        keyword.span(),
+                        "Only one type can be marked as default",
+                    ));
+                }
+                default = Some(types.len());
+            }
+            types.push(input.parse::<Type>()?);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![|]>()?;
+        }
+        if types
+            .iter()
+            .filter(|t| matches!(t, Type::ImplTrait(_)))
+            .count()
+            > 1
+        {
+            return syn_error!("Only one of the types can be a bound (impl Trait)");
+        }
+        Ok(Self {
+            types,
+            default: default.unwrap_or(0),
+        })
+    }
+}
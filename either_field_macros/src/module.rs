@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Visibility};
+
+use crate::minor_parsing::AttrInputs;
+
+// Where the generated items go with `Module: VIS name`
+pub(crate) struct Module {
+    vis: Visibility,
+    name: Ident,
+    reexports: Vec<(Visibility, Ident)>,
+    // the module is public as often as not, so it's documented
+    doc: String,
+    // the template's visibility before it was moved
+    template: Option<(Visibility, Ident)>,
+}
+
+// The visibility an item moved into a child module needs to be
+// visible from the same places it was before
+fn raise(vis: &Visibility) -> Visibility {
+    match vis {
+        Visibility::Inherited => syn::parse_quote! { pub(super) },
+        Visibility::Restricted(restricted) => {
+            let path = &restricted.path;
+            match path.segments.first() {
+                Some(first) if first.ident == "self" => {
+                    let rest = path.segments.iter().skip(1);
+                    syn::parse_quote! { pub(in super #(::#rest)*) }
+                }
+                Some(first) if first.ident == "super" => {
+                    syn::parse_quote! { pub(in super::#path) }
+                }
+                _ => vis.clone(),
+            }
+        }
+        Visibility::Public(_) => vis.clone(),
+    }
+}
+
+impl Module {
+    // Raises the visibilities of the template, its fields and the derived
+    // structs, so that they're generated inside the module as they would've been outside
+    //
+    // `moves_template` is whether the template itself is emitted inside the module
+    pub(crate) fn take(
+        template: &mut syn::ItemStruct,
+        attribute_inputs: &mut AttrInputs,
+        moves_template: bool,
+    ) -> Option<Self> {
+        let settings = &attribute_inputs.settings;
+        let (vis, name) = settings.module.clone()?;
+        let module = Self {
+            vis,
+            name,
+            reexports: settings.reexport.clone(),
+            doc: format!("The items generated for `{}`", template.ident),
+            template: moves_template.then(|| {
+                (
+                    settings
+                        .template_vis
+                        .clone()
+                        .unwrap_or(template.vis.clone()),
+                    settings
+                        .rename_template
+                        .clone()
+                        .unwrap_or(template.ident.clone()),
+                )
+            }),
+        };
+
+        let settings = &mut attribute_inputs.settings;
+        settings.template_vis = settings.template_vis.as_ref().map(raise);
+        template.vis = raise(&template.vis);
+        for field in template.fields.iter_mut() {
+            field.vis = raise(&field.vis);
+        }
+        for derived in &mut attribute_inputs.derived_structs {
+            derived.vis = raise(&derived.vis);
+        }
+        Some(module)
+    }
+
+    // `VIS mod name { use super::*; ... }`, then the template and the
+    // re-exported items are brought back into the parent module
+    pub(crate) fn wrap(self, generated: TokenStream) -> TokenStream {
+        let Self {
+            vis,
+            name,
+            reexports,
+            doc,
+            template,
+        } = self;
+        let template = template.map(|(template_vis, template)| {
+            quote! {
+                #[allow(unused_imports)]
+                #template_vis use #name::#template;
+            }
+        });
+        let reexports = reexports
+            .iter()
+            .map(|(reexport_vis, item)| quote! { #reexport_vis use #name::#item; });
+        quote! {
+            #[doc = #doc]
+            #vis mod #name {
+                #[allow(unused_imports)]
+                use super::*;
+                #generated
+            }
+            #template
+            #(#reexports)*
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, format_ident};
+use syn::{Ident, Type, ext::IdentExt};
+
+use crate::{helper, minor_parsing::AttrInputs};
+
+// What a placeholder of the Naming pattern stands for
+enum Piece {
+    Text(String),
+    Template,
+    Field,
+    Type,
+}
+
+fn parse_pattern(pattern: &syn::LitStr) -> syn::Result<Vec<Piece>> {
+    let value = pattern.value();
+    let mut pieces = vec![];
+    let mut rest = value.as_str();
+    while let Some(start) = rest.find('{') {
+        if start != 0 {
+            pieces.push(Piece::Text(rest[..start].to_string()));
+        }
+        let Some(end) = rest[start..].find('}') else {
+            return Err(syn::Error::new_spanned(pattern, "Unclosed `{` in Naming"));
+        };
+        pieces.push(match &rest[start + 1..start + end] {
+            "Template" => Piece::Template,
+            "Field" => Piece::Field,
+            "Type" => Piece::Type,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    pattern,
+                    format!(
+                        "Unknown placeholder \"{{{other}}}\" in Naming, expected {{Template}}, {{Field}} or {{Type}}"
+                    ),
+                ));
+            }
+        });
+        rest = &rest[start + end + 1..];
+    }
+    if !rest.is_empty() {
+        pieces.push(Piece::Text(rest.to_string()));
+    }
+    Ok(pieces)
+}
+
+// `PlayerData` for `PlayerData`, `VecString` for `Vec<String>`, `U8` for `&'a u8`
+fn type_label(ty: &Type) -> String {
+    fn push_idents(tokens: TokenStream, out: &mut String) {
+        let mut is_lifetime = false;
+        for tt in tokens {
+            match &tt {
+                TokenTree::Ident(ident) if !is_lifetime => {
+                    out.push_str(&helper::to_camel_case(&ident.unraw().to_string()));
+                }
+                TokenTree::Group(group) => push_idents(group.stream(), out),
+                _ => (),
+            }
+            is_lifetime = matches!(&tt, TokenTree::Punct(punct) if punct.as_char() == '\'');
+        }
+    }
+    let mut out = String::new();
+    push_idents(ty.to_token_stream(), &mut out);
+    match out.is_empty() {
+        true => String::from("Unit"),
+        false => out,
+    }
+}
+
+// Derived structs written as `_: [...]` get named by the Naming pattern,
+// where `{Template}` is the name of the template, `{Field}` the fields the
+// derived struct gives a type to and `{Type}` those types, i.e. `{Template}With{Field}`
+//
+// Then, no two derived structs can have the same name
+pub(crate) fn name_derived_structs(
+    attribute_inputs: &mut AttrInputs,
+    template: &Ident,
+    fields: &syn::Fields,
+) -> syn::Result<()> {
+    let keys: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .map(|(field_number, field)| match &field.ident {
+            Some(ident) => (
+                ident.unraw().to_string(),
+                helper::to_camel_case(&ident.unraw().to_string()),
+            ),
+            None => (field_number.to_string(), format!("Field{field_number}")),
+        })
+        .collect();
+    let pattern = match &attribute_inputs.settings.naming {
+        Some(pattern) => Some(parse_pattern(pattern)?),
+        None => None,
+    };
+
+    for derived in &mut attribute_inputs.derived_structs {
+        if !derived.unnamed {
+            continue;
+        }
+        let Some(pattern) = &pattern else {
+            return Err(syn::Error::new(
+                derived.name.span(),
+                "Derived structs can only be left unnamed with a Naming pattern, i.e. `Naming: \"{Template}With{Field}\";`",
+            ));
+        };
+        // the fields given a type, in the order of the template
+        let given: Vec<_> = keys
+            .iter()
+            .filter_map(|(key, label)| match derived.fields.get(key) {
+                Some(Type::Infer(_)) | None => None,
+                Some(ty) => Some((label, ty)),
+            })
+            .collect();
+
+        let mut name = String::new();
+        for piece in pattern {
+            match piece {
+                Piece::Text(text) => name.push_str(text),
+                Piece::Template => name.push_str(&template.to_string()),
+                Piece::Field | Piece::Type if given.is_empty() => {
+                    return Err(syn::Error::new(
+                        derived.name.span(),
+                        "The Naming pattern uses the fields given a type, but this derived struct doesn't give any",
+                    ));
+                }
+                Piece::Field => name.push_str(
+                    &given
+                        .iter()
+                        .map(|(label, _)| label.as_str())
+                        .collect::<Vec<_>>()
+                        .join("And"),
+                ),
+                Piece::Type => name.push_str(
+                    &given
+                        .iter()
+                        .map(|(_, ty)| type_label(ty))
+                        .collect::<Vec<_>>()
+                        .join("And"),
+                ),
+            }
+        }
+        if syn::parse_str::<Ident>(&name).is_err() {
+            return Err(syn::Error::new(
+                derived.name.span(),
+                format!(
+                    "The Naming pattern names this derived struct \"{name}\", which isn't a valid name"
+                ),
+            ));
+        }
+        derived.name = format_ident!("{}", name, span = derived.name.span());
+    }
+
+    let derived_structs = &attribute_inputs.derived_structs;
+    for (position, derived) in derived_structs.iter().enumerate() {
+        let named_by = match derived.unnamed {
+            true => " (named by the Naming pattern)",
+            false => "",
+        };
+        if derived_structs[..position]
+            .iter()
+            .any(|other| other.name == derived.name)
+        {
+            return Err(syn::Error::new(
+                derived.name.span(),
+                format!(
+                    "More than one derived struct is named \"{}\"{}",
+                    derived.name, named_by
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::{fmt::Write, path::PathBuf};
+
+use syn::Ident;
+
+use crate::{
+    helper::pretty,
+    minor_parsing::Settings,
+    template::{TemplateField, Variant},
+};
+
+// Setting this while compiling writes a report for every template, in that directory
+//
+// Cargo doesn't know the macro reads it, so changing it doesn't rebuild
+// anything: crates that were already built don't write their reports until they're rebuilt
+const REPORT_DIR_VAR: &str = "EITHER_FIELD_REPORT_DIR";
+
+pub(crate) fn is_enabled(settings: &Settings) -> bool {
+    settings.report || std::env::var_os(REPORT_DIR_VAR).is_some()
+}
+
+// What every derived struct ended up being
+//
+// `declaration` is what a derived struct is declared as, given its name
+pub(crate) fn gen_report(
+    template: &Ident,
+    fields: &[TemplateField],
+    variants: &[Variant],
+    settings: &Settings,
+    declaration: impl Fn(&Variant) -> String,
+) -> String {
+    let mut out = String::new();
+    let mode = match settings.generate_structs {
+        true => "structs",
+        false => "type declarations",
+    };
+    let _ = writeln!(out, "Template {template} ({mode})");
+    let _ = writeln!(out, "  fields:");
+    for field in fields {
+        let _ = match &field.either {
+            Some(either) => writeln!(
+                out,
+                "    {}: either!({}), defaulting to {}",
+                field.key,
+                either
+                    .types
+                    .iter()
+                    .map(pretty)
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+                pretty(either.default_type())
+            ),
+            None => writeln!(out, "    {}: {}", field.key, pretty(&field.field.ty)),
+        };
+    }
+
+    let _ = writeln!(out, "  derived structs:");
+    for variant in variants {
+        let _ = writeln!(out, "    {}", declaration(variant));
+        let mut omitted = vec![];
+        for (field_number, (field, ty)) in fields.iter().zip(&variant.types).enumerate() {
+            if variant.is_omitted(field_number, settings) {
+                omitted.push(field.key.as_str());
+                continue;
+            }
+            let _ = writeln!(out, "      {}: {}", field.key, pretty(ty));
+        }
+        if !omitted.is_empty() {
+            let _ = writeln!(out, "      omitted: {}", omitted.join(", "));
+        }
+    }
+    out
+}
+
+// The template's name can't tell templates in different modules apart, and
+// proc macros can't see module paths, so the file and line of the macro's call are
+// used instead, i.e. `Score-src_scores_rs-12` for `Score` on line 12 of `src/scores.rs`
+fn report_name(template: &Ident) -> String {
+    let call_site = proc_macro::Span::call_site();
+    let file: String = call_site
+        .file()
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect();
+    format!("{template}-{file}-{}", call_site.line())
+}
+
+// Writes the report to `<template>-<file>-<line>.txt`, in the directory from
+// EITHER_FIELD_REPORT_DIR, or otherwise `either_field` in the OUT_DIR of the crate using the macro
+pub(crate) fn write_report(template: &Ident, report: &str) -> Result<(), String> {
+    let dir = match (
+        std::env::var_os(REPORT_DIR_VAR),
+        std::env::var_os("OUT_DIR"),
+    ) {
+        (Some(dir), _) => PathBuf::from(dir),
+        (None, Some(dir)) => PathBuf::from(dir).join("either_field"),
+        (None, None) => {
+            return Err(format!(
+                "Report needs a place to write the report of \"{template}\": either set {REPORT_DIR_VAR}, or add a build script so that OUT_DIR is set"
+            ));
+        }
+    };
+    std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(dir.join(format!("{}.txt", report_name(template))), report))
+        .map_err(|e| format!("Couldn't write the report of \"{template}\": {e}"))
+}
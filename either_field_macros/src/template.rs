@@ -0,0 +1,237 @@
+use quote::ToTokens;
+use syn::{Expr, Type, ext::IdentExt};
+
+use crate::{
+    helper,
+    minor_parsing::{Derived, EitherMacro, Settings},
+};
+
+// This is a field of the template, already stripped of
+// the attributes that are only meant for the macro
+//
+// `key` is the field's name, or its position for tuple structs
+pub(crate) struct TemplateField {
+    pub key: String,
+    pub field: syn::Field,
+    pub either: Option<EitherMacro>,
+    pub default_value: Option<Expr>,
+}
+
+// This is a derived struct, with the type
+// every field of the template ends up having
+pub(crate) struct Variant<'a> {
+    pub derived: &'a Derived,
+    pub types: Vec<Type>,
+}
+
+pub(crate) fn parse_fields(fields: &mut syn::Fields) -> syn::Result<Vec<TemplateField>> {
+    let mut out = vec![];
+    for (field_number, field) in fields.iter_mut().enumerate() {
+        let mut default_value = None;
+        let mut attrs = vec![];
+        for attr in std::mem::take(&mut field.attrs) {
+            match attr.path().is_ident("default") {
+                true => default_value = Some((attr.parse_args::<Expr>()?, attr)),
+                false => attrs.push(attr),
+            }
+        }
+        field.attrs = attrs;
+
+        let either = match helper::get_macro_from_type(&field.ty) {
+            Some(type_macro) => Some(syn::parse2::<EitherMacro>(type_macro.tokens)?),
+            None => None,
+        };
+        // a single value can't fit every type the field can have
+        if let (Some(_), Some((_, attr))) = (&either, &default_value) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`#[default(expr)]` can't be used on `either!` fields, give the value in the derived structs instead, i.e. `field: i32 = 0`",
+            ));
+        }
+
+        out.push(TemplateField {
+            key: match field.ident.as_ref() {
+                Some(v) => v.unraw().to_string(),
+                None => field_number.to_string(),
+            },
+            field: field.clone(),
+            either,
+            default_value: default_value.map(|(value, _)| value),
+        });
+    }
+    Ok(out)
+}
+
+// Picks the type of every field for a derived struct.
+//
+// `allow_overrides` lets the derived struct change the type
+// of fields which aren't using `either!`
+pub(crate) fn resolve<'a>(
+    fields: &[TemplateField],
+    derived: &'a Derived,
+    settings: &Settings,
+    allow_overrides: bool,
+) -> Result<Variant<'a>, String> {
+    let mut types = vec![];
+    for field in fields {
+        let Some(either) = &field.either else {
+            types.push(match derived.fields.get(&field.key) {
+                Some(v) if allow_overrides && !matches!(v, Type::Infer(_)) => v.clone(),
+                _ => field.field.ty.clone(),
+            });
+            continue;
+        };
+
+        match derived.fields.get(&field.key) {
+            None if settings.require_all_fields && either.types.len() > 1 => {
+                return Err(format!(
+                    "Field \"{}\" of struct \"{}\" has no type specified, which RequireAllFields forbids",
+                    field.key, derived.name
+                ));
+            }
+            Some(Type::Infer(_)) | None if !either.has_default_type() => {
+                return Err(format!(
+                    "Field \"{}\" of struct \"{}\" has no type specified, and its default is the bound \"{}\", which isn't a type",
+                    field.key,
+                    derived.name,
+                    either.default_type().to_token_stream()
+                ));
+            }
+            Some(Type::Infer(_)) | None => types.push(either.default_type().clone()),
+            Some(Type::ImplTrait(_)) => {
+                return Err(format!(
+                    "Struct \"{}\" has to pick a type satisfying the bound of field \"{}\", not the bound itself",
+                    derived.name, field.key
+                ));
+            }
+            Some(v) if either.types.contains(v) || either.bound().is_some() => {
+                types.push(v.clone())
+            }
+            Some(v) => {
+                return Err(format!(
+                    "Type \"{}\" (struct \"{}\", field \"{}\") is not part of the specified possible types: {:?}",
+                    v.to_token_stream(),
+                    derived.name,
+                    field.key,
+                    either
+                        .types
+                        .iter()
+                        .map(|x| x.to_token_stream().to_string())
+                        .collect::<Vec<_>>()
+                ));
+            }
+        }
+    }
+    Ok(Variant { derived, types })
+}
+
+pub(crate) fn is_empty_tuple(t: &Type) -> bool {
+    matches!(t, Type::Tuple(syn::TypeTuple { elems, .. }) if elems.is_empty())
+}
+
+// Whether the type is `either_field::Absent`, written either with that path
+// (optionally with a leading `::`), or as a bare `Absent` which is taken to be
+// the imported one. Other types named `Absent`, like `mine::Absent`, are left alone
+pub(crate) fn is_absent(t: &Type) -> bool {
+    let Type::Path(syn::TypePath { qself: None, path }) = t else {
+        return false;
+    };
+    if path.segments.iter().any(|x| !x.arguments.is_none()) {
+        return false;
+    }
+    let segments: Vec<_> = path.segments.iter().map(|x| x.ident.to_string()).collect();
+    match segments.as_slice() {
+        [absent] => path.leading_colon.is_none() && absent == "Absent",
+        [krate, absent] => krate == "either_field" && absent == "Absent",
+        _ => false,
+    }
+}
+
+// Serde attributes can only be added when serde's derives are there to read them
+pub(crate) fn derives_serde(attrs: &[syn::Attribute]) -> bool {
+    helper::derives(attrs, "Serialize") || helper::derives(attrs, "Deserialize")
+}
+
+// Whether a field of this type is left out with the settings,
+// when generating structs
+pub(crate) fn is_omittable(t: &Type, settings: &Settings) -> bool {
+    (settings.delete_empty_tuple_fields && is_empty_tuple(t))
+        || (settings.omit_absent_fields && is_absent(t))
+}
+
+impl Variant<'_> {
+    // Whether the field is left out of the generated struct
+    pub fn is_omitted(&self, field_number: usize, settings: &Settings) -> bool {
+        settings.generate_structs && is_omittable(&self.types[field_number], settings)
+    }
+}
+
+// The variants that are actually different types.
+//
+// Type aliases picking the same types point to the same
+// type, so anything implemented on them must only be emitted once
+pub(crate) fn distinct<'a, 'b>(
+    variants: &'b [Variant<'a>],
+    settings: &Settings,
+) -> Vec<&'b Variant<'a>> {
+    let mut seen = std::collections::HashSet::new();
+    variants
+        .iter()
+        .filter(|variant| {
+            settings.generate_structs
+                || seen.insert(
+                    variant
+                        .types
+                        .iter()
+                        .map(|t| t.to_token_stream().to_string())
+                        .collect::<Vec<_>>(),
+                )
+        })
+        .collect()
+}
+
+// Unions are handled as structs with named fields,
+// and turned back into unions when emitted
+pub(crate) fn from_item(item: syn::Item) -> syn::Result<(syn::ItemStruct, bool)> {
+    match item {
+        syn::Item::Struct(item) => Ok((item, false)),
+        syn::Item::Union(item) => Ok((
+            syn::ItemStruct {
+                attrs: item.attrs,
+                vis: item.vis,
+                struct_token: syn::token::Struct(item.union_token.span),
+                ident: item.ident,
+                generics: item.generics,
+                fields: syn::Fields::Named(item.fields),
+                semi_token: None,
+            },
+            true,
+        )),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "Templates can only be structs or unions",
+        )),
+    }
+}
+
+pub(crate) fn item_tokens(item: syn::ItemStruct, is_union: bool) -> proc_macro2::TokenStream {
+    match (is_union, item.fields) {
+        (true, syn::Fields::Named(fields)) => syn::ItemUnion {
+            attrs: item.attrs,
+            vis: item.vis,
+            union_token: syn::token::Union(item.struct_token.span),
+            ident: item.ident,
+            generics: item.generics,
+            fields,
+        }
+        .into_token_stream(),
+        (_, fields) => syn::ItemStruct { fields, ..item }.into_token_stream(),
+    }
+}
+
+// Whether the type is `ManuallyDrop<...>`, which
+// unions accept even if it doesn't implement `Copy`
+pub(crate) fn is_manually_drop(t: &Type) -> bool {
+    matches!(t, Type::Path(syn::TypePath { path, .. })
+        if path.segments.last().is_some_and(|segment| segment.ident == "ManuallyDrop"))
+}
@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{GenericParam, Generics, Ident, Index};
+
+use crate::{
+    helper,
+    minor_parsing::Settings,
+    template::{TemplateField, Variant},
+};
+
+// Builds `Name { field: value, ... }` or `Name(value, ...)`
+// out of the values of the fields that aren't omitted
+pub(crate) fn construct(
+    name: &TokenStream,
+    fields: &[(&TemplateField, TokenStream)],
+    is_tuple: bool,
+) -> TokenStream {
+    let values = fields.iter().map(|(_, value)| value);
+    match is_tuple {
+        true => quote! { #name(#(#values),*) },
+        false => {
+            let idents = fields.iter().map(|(field, _)| &field.field.ident);
+            quote! { #name { #(#idents: #values),* } }
+        }
+    }
+}
+
+// Accesses a field of `self`, `position` being where the field is
+// within the struct, which for tuple structs changes with omitted fields
+pub(crate) fn access(field: &TemplateField, position: usize) -> TokenStream {
+    match &field.field.ident {
+        Some(ident) => quote! { self.#ident },
+        None => {
+            let index = Index::from(position);
+            quote! { self.#index }
+        }
+    }
+}
+
+// The arguments to use the generics with, replacing
+// the parameter `from` with `to` if specified
+pub(crate) fn generic_args(generics: &Generics, replace: Option<(&Ident, &Ident)>) -> TokenStream {
+    let args = generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(x) => {
+            let lifetime = &x.lifetime;
+            quote! { #lifetime }
+        }
+        GenericParam::Type(x) => match replace {
+            Some((from, to)) if x.ident == *from => quote! { #to },
+            _ => {
+                let ident = &x.ident;
+                quote! { #ident }
+            }
+        },
+        GenericParam::Const(x) => {
+            let ident = &x.ident;
+            quote! { #ident }
+        }
+    });
+    quote! { <#(#args),*> }
+}
+
+fn method_name(field: &TemplateField, prefix: &str) -> Ident {
+    match &field.field.ident {
+        Some(ident) => format_ident!("{}_{}", prefix, syn::ext::IdentExt::unraw(ident)),
+        None => format_ident!("{}_{}", prefix, field.key),
+    }
+}
+
+// In type declaration mode, `with_field()` changes the generic parameter of
+// the field, returning whichever instance of the template has the new type.
+//
+// `template` is the template after its fields have been turned into generics
+pub(crate) fn gen_generic_transitions(
+    template: &syn::ItemStruct,
+    fields: &[TemplateField],
+    taken_idents: &mut HashSet<String>,
+) -> TokenStream {
+    let name = &template.ident;
+    let vis = &template.vis;
+    let is_tuple = matches!(template.fields, syn::Fields::Unnamed(_));
+    let new_generic = helper::generate_generic_name("with", taken_idents);
+    let (impl_generics, ty_generics, where_clause) = template.generics.split_for_impl();
+
+    let mut methods = vec![];
+    for (field_number, (field, template_field)) in template.fields.iter().zip(fields).enumerate() {
+        if template_field.either.is_none() {
+            continue;
+        }
+        let syn::Type::Verbatim(generic) = &field.ty else {
+            continue;
+        };
+        let Ok(generic) = syn::parse2::<Ident>(generic.clone()) else {
+            continue;
+        };
+
+        let output_args = generic_args(&template.generics, Some((&generic, &new_generic)));
+        let values: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .map(|(other_number, other)| match other_number == field_number {
+                true => (other, quote! { value }),
+                false => (other, access(other, other_number)),
+            })
+            .collect();
+        let body = construct(&quote! { #name }, &values, is_tuple);
+        let method = method_name(template_field, "with");
+        let bounds = helper::bounds_of(&template.generics, &generic);
+        let doc = format!("Replaces `{}`, changing its type", template_field.key);
+        methods.push(quote! {
+            #[doc = #doc]
+            #vis fn #method<#new_generic: #bounds>(self, value: #new_generic) -> #name #output_args {
+                #body
+            }
+        });
+    }
+
+    quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
+// With generated structs, `with_field()` turns a derived struct into the one
+// that only differs in that field's type.
+//
+// When there's more than one derived struct to go to, the method is generic
+// over the new type, and goes through a trait implemented once per destination
+pub(crate) fn gen_struct_transitions(
+    template_ident: &Ident,
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+    is_tuple: bool,
+    taken_idents: &mut HashSet<String>,
+) -> TokenStream {
+    let mut out = TokenStream::new();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let new_generic = helper::generate_generic_name("with", taken_idents);
+
+    for (field_number, field) in fields.iter().enumerate() {
+        if field.either.is_none() {
+            continue;
+        }
+        let method = method_name(field, "with");
+        let transition_trait = format_ident!(
+            "__EitherField{}With{}",
+            template_ident,
+            helper::to_camel_case(&field.key)
+        );
+        let mut trait_needed = false;
+
+        for variant in variants {
+            let mut targets: Vec<&Variant> = vec![];
+            for other in variants {
+                let differs_only_here = other.types[field_number] != variant.types[field_number]
+                    && (0..fields.len())
+                        .all(|n| n == field_number || other.types[n] == variant.types[n]);
+                // derived structs which are identical can't be told apart
+                if differs_only_here
+                    && !targets
+                        .iter()
+                        .any(|target| target.types[field_number] == other.types[field_number])
+                {
+                    targets.push(other);
+                }
+            }
+
+            for target in &targets {
+                let mut position = 0;
+                let mut values = vec![];
+                for (other_number, other) in fields.iter().enumerate() {
+                    let present = !variant.is_omitted(other_number, settings);
+                    if !target.is_omitted(other_number, settings) {
+                        values.push(match other_number == field_number {
+                            true => (other, quote! { value }),
+                            false => (other, access(other, position)),
+                        });
+                    }
+                    if present {
+                        position += 1;
+                    }
+                }
+
+                let variant_name = &variant.derived.name;
+                let target_name = &target.derived.name;
+                let new_type = &target.types[field_number];
+                let body = construct(&quote! { #target_name }, &values, is_tuple);
+                let vis = &variant.derived.vis;
+                let doc = format!("Turns it into `{target_name}`, replacing `{}`", field.key);
+                match targets.len() {
+                    1 => out.extend(quote! {
+                        impl #impl_generics #variant_name #ty_generics #where_clause {
+                            #[doc = #doc]
+                            #[allow(unused_variables)]
+                            #vis fn #method(self, value: #new_type) -> #target_name #ty_generics {
+                                #body
+                            }
+                        }
+                    }),
+                    _ => {
+                        trait_needed = true;
+                        out.extend(quote! {
+                            impl #impl_generics #transition_trait<#new_type> for #variant_name #ty_generics #where_clause {
+                                type Output = #target_name #ty_generics;
+                                #[allow(unused_variables)]
+                                fn __with(self, value: #new_type) -> Self::Output {
+                                    #body
+                                }
+                            }
+                        });
+                    }
+                }
+            }
+
+            if targets.len() > 1 {
+                let variant_name = &variant.derived.name;
+                let vis = &variant.derived.vis;
+                let doc = format!(
+                    "Turns it into the derived struct with the new `{}`, whichever its type",
+                    field.key
+                );
+                out.extend(quote! {
+                    impl #impl_generics #variant_name #ty_generics #where_clause {
+                        #[doc = #doc]
+                        #vis fn #method<#new_generic>(self, value: #new_generic) -> <Self as #transition_trait<#new_generic>>::Output
+                        where
+                            Self: #transition_trait<#new_generic>,
+                        {
+                            #transition_trait::__with(self, value)
+                        }
+                    }
+                });
+            }
+        }
+
+        if trait_needed {
+            out.extend(quote! {
+                #[doc(hidden)]
+                pub trait #transition_trait<T> {
+                    type Output;
+                    fn __with(self, value: T) -> Self::Output;
+                }
+            });
+        }
+    }
+    out
+}
@@ -0,0 +1,172 @@
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::quote;
+use syn::{GenericArgument, Ident, PathArguments, Token, Type, ext::IdentExt, parse::Parse};
+
+use crate::{docs, minor_parsing::AttrInputs};
+
+// This is the struct that handles parsing the input of variants!
+//
+// template => attribute input
+// type path::Type<field_name, field_name = type, ...> => derived structs
+pub(crate) enum VariantsInput {
+    Template {
+        attr: TokenStream,
+        item: TokenStream,
+    },
+    Existing(Box<Existing>),
+}
+impl Parse for VariantsInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![type]) {
+            return Ok(Self::Existing(Box::new(input.parse()?)));
+        }
+
+        // everything up to the first `=>` outside of any group is the template
+        let mut item = TokenStream::new();
+        let mut tokens = input.parse::<TokenStream>()?.into_iter().peekable();
+        while let Some(tt) = tokens.next() {
+            match (&tt, tokens.peek()) {
+                (TokenTree::Punct(eq), Some(TokenTree::Punct(gt)))
+                    if eq.as_char() == '='
+                        && eq.spacing() == Spacing::Joint
+                        && gt.as_char() == '>' =>
+                {
+                    tokens.next();
+                    return Ok(Self::Template {
+                        attr: tokens.collect(),
+                        item,
+                    });
+                }
+                _ => item.extend([tt]),
+            }
+        }
+        Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "Expected `=>` followed by the derived structs after the template",
+        ))
+    }
+}
+
+// An existing generic type, whose type parameters
+// are named after the fields they stand for
+pub(crate) struct Existing {
+    path: syn::Path,
+    fields: Vec<(Ident, Option<Type>)>,
+    attribute_inputs: AttrInputs,
+}
+impl Parse for Existing {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let _ = input.parse::<Token![type]>()?;
+        let mut path = input.parse::<syn::Path>()?;
+        let Some(last) = path.segments.last_mut() else {
+            return Err(input.error("Expected a type"));
+        };
+
+        let mut fields = vec![];
+        match std::mem::take(&mut last.arguments) {
+            PathArguments::AngleBracketed(arguments) => {
+                for argument in arguments.args {
+                    match argument {
+                        GenericArgument::Type(Type::Path(syn::TypePath { qself: None, path }))
+                            if path.get_ident().is_some() =>
+                        {
+                            fields.push((path.get_ident().unwrap().clone(), None));
+                        }
+                        GenericArgument::AssocType(binding) if binding.generics.is_none() => {
+                            fields.push((binding.ident, Some(binding.ty)));
+                        }
+                        other => {
+                            return Err(syn::Error::new_spanned(
+                                other,
+                                "Expected the name of a field, optionally followed by `= DefaultType`",
+                            ));
+                        }
+                    }
+                }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    last,
+                    "Expected the type's parameters, named after the fields they stand for",
+                ));
+            }
+        }
+
+        let _ = input.parse::<Token![=>]>()?;
+        Ok(Self {
+            path,
+            fields,
+            attribute_inputs: input.parse()?,
+        })
+    }
+}
+impl Existing {
+    pub fn expand(self) -> syn::Result<TokenStream> {
+        if self.attribute_inputs.has_settings {
+            return Err(syn::Error::new_spanned(
+                &self.path,
+                "Settings can't be used when declaring variants of an existing type",
+            ));
+        }
+
+        let mut out = TokenStream::new();
+        let path = &self.path;
+        for derived in &self.attribute_inputs.derived_structs {
+            if derived.unnamed {
+                return Err(syn::Error::new(
+                    derived.name.span(),
+                    "Derived structs of an existing type have to be named, as Naming can't be used",
+                ));
+            }
+            if let Some(key) = derived
+                .fields
+                .keys()
+                .find(|key| !self.fields.iter().any(|(ident, _)| ident.unraw() == **key))
+            {
+                return Err(syn::Error::new_spanned(
+                    &derived.name,
+                    format!(
+                        "\"{}\" has no field \"{}\"",
+                        path.segments.last().unwrap().ident,
+                        key
+                    ),
+                ));
+            }
+            if !derived.values.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &derived.name,
+                    "Default values can't be used when declaring variants of an existing type",
+                ));
+            }
+
+            let mut types = vec![];
+            for (ident, default) in &self.fields {
+                match (derived.fields.get(&ident.unraw().to_string()), default) {
+                    (Some(Type::Infer(_)) | None, Some(default)) => types.push(default),
+                    (Some(Type::Infer(_)) | None, None) => {
+                        return Err(syn::Error::new_spanned(
+                            &derived.name,
+                            format!("Field \"{ident}\" has no type specified, and no default type"),
+                        ));
+                    }
+                    (Some(ty), _) => types.push(ty),
+                }
+            }
+
+            let vis = &derived.vis;
+            let name = &derived.name;
+            out.extend(docs::gen_docs(
+                derived,
+                &path.segments.last().unwrap().ident.to_string(),
+                self.fields
+                    .iter()
+                    .zip(&types)
+                    .map(|((ident, _), ty)| (ident.to_string(), Some(*ty))),
+            ));
+            out.extend(quote! {
+                #vis type #name = #path<#(#types),*>;
+            });
+        }
+        Ok(out)
+    }
+}
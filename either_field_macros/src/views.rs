@@ -0,0 +1,123 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Generics, Type};
+
+use crate::{
+    assertions, helper,
+    minor_parsing::Settings,
+    template::{TemplateField, Variant},
+};
+
+// Types which are known to be zero-sized just by looking at them: `()` and
+// `PhantomData`, written with its full path, since any other type could be named `PhantomData`
+fn is_zero_sized(t: &Type) -> bool {
+    match t {
+        Type::Tuple(syn::TypeTuple { elems, .. }) => elems.is_empty(),
+        Type::Path(syn::TypePath { qself: None, path }) => {
+            let segments: Vec<_> = path.segments.iter().map(|x| x.ident.to_string()).collect();
+            path.leading_colon.is_some()
+                && matches!(
+                    segments.as_slice(),
+                    [krate, marker, phantom]
+                        if (krate == "core" || krate == "std") && marker == "marker" && phantom == "PhantomData"
+                )
+                && path.segments.iter().take(2).all(|x| x.arguments.is_none())
+        }
+        _ => false,
+    }
+}
+
+// For every pair of derived structs that only differ in zero-sized fields,
+// generates `as_other(&self) -> &Other`, reinterpreting the reference.
+//
+// That's only sound with `#[repr(C)]`, where the layout only depends on the fields,
+// and it's also checked when compiling, to be sure nothing was overlooked
+pub(crate) fn gen_views(
+    attrs: &[syn::Attribute],
+    fields: &[TemplateField],
+    variants: &[&Variant],
+    generics: &Generics,
+    settings: &Settings,
+) -> Result<TokenStream, String> {
+    if !assertions::is_repr_c(attrs) {
+        return Err(String::from(
+            "GenViews requires the template to be #[repr(C)]",
+        ));
+    }
+
+    let mut out = TokenStream::new();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    for variant in variants {
+        let mut methods = vec![];
+        for other in variants {
+            let compatible = variant.types != other.types
+                && variant
+                    .types
+                    .iter()
+                    .zip(&other.types)
+                    .all(|(a, b)| a == b || (is_zero_sized(a) && is_zero_sized(b)));
+            if !compatible {
+                continue;
+            }
+
+            let name = &variant.derived.name;
+            let other_name = &other.derived.name;
+            let mut checks = vec![
+                quote! {
+                    ::core::mem::size_of::<#name #ty_generics>() == ::core::mem::size_of::<#other_name #ty_generics>()
+                },
+                quote! {
+                    ::core::mem::align_of::<#name #ty_generics>() == ::core::mem::align_of::<#other_name #ty_generics>()
+                },
+            ];
+            // the fields which differ have to really be zero-sized
+            for (a, b) in variant.types.iter().zip(&other.types) {
+                if a != b {
+                    for t in [a, b] {
+                        checks.push(quote! {
+                            ::core::mem::size_of::<#t>() == 0 && ::core::mem::align_of::<#t>() == 1
+                        });
+                    }
+                }
+            }
+            for (field_number, field) in fields.iter().enumerate() {
+                if variant.is_omitted(field_number, settings)
+                    || other.is_omitted(field_number, settings)
+                {
+                    continue;
+                }
+                let member = assertions::position_member(field, field_number, variant, settings);
+                let other_member =
+                    assertions::position_member(field, field_number, other, settings);
+                checks.push(quote! {
+                    ::core::mem::offset_of!(#name #ty_generics, #member)
+                        == ::core::mem::offset_of!(#other_name #ty_generics, #other_member)
+                });
+            }
+
+            let method = format_ident!("as_{}", helper::to_snake_case(&other_name.to_string()));
+            let message = format!("\"{name}\" and \"{other_name}\" don't have the same layout");
+            let vis = &variant.derived.vis;
+            let doc = format!("Views it as `{other_name}`, without copying anything");
+            methods.push(quote! {
+                #[doc = #doc]
+                #vis fn #method(&self) -> &#other_name #ty_generics {
+                    const { ::core::assert!(#(#checks)&&*, #message) };
+                    // SAFETY: both are #[repr(C)] and only differ in zero-sized fields,
+                    // which was checked above
+                    unsafe { &*(self as *const Self as *const #other_name #ty_generics) }
+                }
+            });
+        }
+
+        if !methods.is_empty() {
+            let name = &variant.derived.name;
+            out.extend(quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #(#methods)*
+                }
+            });
+        }
+    }
+    Ok(out)
+}
@@ -46,11 +46,7 @@ pub fn test() {
     let another_omitted_1 = AnotherOmitsEverythingBut1 {
         either_type_1: 33u32,
     };
-    let another_generic = AnotherGenericStruct {
-        either_type_0: (),
-        either_type_1: (),
-        either_type_2: (),
-    };
+    let another_generic = AnotherGenericStruct {};
     println!("{omitted_0:#?}");
     println!("{omitted_1:#?}");
     println!("{omitted_2:#?}");
@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 // Showcases how you can make a functionality similar to Omit<T, field> in Typescript
 // 1.0.0
 mod usage1;
@@ -0,0 +1,25 @@
+// Templates used by other crates, see should_pass/extend_template
+
+#[either_field::make_template(
+    ExportTemplate: true;
+    pub ScoreWithId: [player: u64]
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Score<T> {
+    pub player: either_field::either!(u64 | String | (u64, String)),
+    pub value: T,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    DeleteTemplate: true,
+    GenTransitions: true,
+    ExportTemplate: true;
+    pub RawEntry: [key: u32]
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub key: either_field::either!(u32 | String),
+    #[default(0)]
+    pub hits: u64,
+}
@@ -1,13 +1,52 @@
+pub mod exported;
+
 #[test]
 fn errors() {
     let t = trybuild::TestCases::new();
     t.compile_fail("src/should_fail/not_included/mod.rs");
+    t.compile_fail("src/should_fail/require_all_fields/mod.rs");
+    t.compile_fail("src/should_fail/template_impl_unknown_variant/mod.rs");
+    t.compile_fail("src/should_fail/union_not_copy/mod.rs");
+    t.compile_fail("src/should_fail/assert_layout_mismatch/mod.rs");
+    t.compile_fail("src/should_fail/variants_missing_type/mod.rs");
+    t.compile_fail("src/should_fail/extend_template_settings/mod.rs");
+    t.compile_fail("src/should_fail/derive_not_generic/mod.rs");
+    t.compile_fail("src/should_fail/bound_not_satisfied/mod.rs");
+    t.compile_fail("src/should_fail/assert_impl_not_send/mod.rs");
+    t.compile_fail("src/should_fail/naming_collision/mod.rs");
+    t.compile_fail("src/should_fail/template_options_types/mod.rs");
+    t.compile_fail("src/should_fail/default_values_conflicts/mod.rs");
+    t.compile_fail("src/should_fail/views_look_alike/mod.rs");
 }
 
 #[test]
 fn successes() {
     let t = trybuild::TestCases::new();
-    t.pass("../example/src/usage1.rs");
-    t.pass("../example/src/usage2.rs");
-    t.pass("../example/src/latest_feature.rs");
+    t.pass("src/should_pass/usage1/mod.rs");
+    t.pass("src/should_pass/usage2/mod.rs");
+    t.pass("src/should_pass/latest_feature/mod.rs");
+    t.pass("src/should_pass/generic_names/mod.rs");
+    t.pass("src/should_pass/default_marker/mod.rs");
+    t.pass("src/should_pass/default_values/mod.rs");
+    t.pass("src/should_pass/template_impl/mod.rs");
+    t.pass("src/should_pass/variant_impl/mod.rs");
+    t.pass("src/should_pass/transitions/mod.rs");
+    t.pass("src/should_pass/maps/mod.rs");
+    t.pass("src/should_pass/tuple_types/mod.rs");
+    t.pass("src/should_pass/unions/mod.rs");
+    t.pass("src/should_pass/assert_layout/mod.rs");
+    t.pass("src/should_pass/views/mod.rs");
+    t.pass("src/should_pass/variants/mod.rs");
+    t.pass("src/should_pass/extend_template/mod.rs");
+    t.pass("src/should_pass/derive/mod.rs");
+    t.pass("src/should_pass/absent/mod.rs");
+    t.pass("src/should_pass/traits/mod.rs");
+    t.pass("src/should_pass/traits_layouts/mod.rs");
+    t.pass("src/should_pass/accessors/mod.rs");
+    t.pass("src/should_pass/bounds/mod.rs");
+    t.pass("src/should_pass/assert_impl/mod.rs");
+    t.pass("src/should_pass/docs/mod.rs");
+    t.pass("src/should_pass/naming/mod.rs");
+    t.pass("src/should_pass/module/mod.rs");
+    t.pass("src/should_pass/template_options/mod.rs");
 }
@@ -0,0 +1,14 @@
+use std::rc::Rc;
+
+#[either_field::make_template(
+    GenStructs: true,
+    AssertImpl: [Send, Clone];
+    ScoreWithId: [player: u64],
+    ScoreWithName: [player: Rc<str>]
+)]
+struct Score {
+    player: either_field::either!(u64 | Rc<str>),
+    value: i32,
+}
+
+fn main() {}
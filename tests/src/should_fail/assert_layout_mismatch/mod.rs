@@ -0,0 +1,17 @@
+#[either_field::make_template(
+    GenStructs: true,
+    AssertLayout: true;
+    Small: [
+        payload: u8
+    ],
+    Large: [
+        payload: u64
+    ]
+)]
+#[repr(C)]
+struct Packet {
+    payload: either_field::either!(u8 | u64),
+    trailer: u32,
+}
+
+fn main() {}
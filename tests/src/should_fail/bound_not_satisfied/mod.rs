@@ -0,0 +1,20 @@
+struct NotDisplay;
+
+#[either_field::make_template(
+    Labelled: [label: NotDisplay, note: NotDisplay],
+    Other: [label: String]
+)]
+struct Reading {
+    label: either_field::either!(impl std::fmt::Display),
+    note: either_field::either!(() | impl Into<String>),
+}
+
+#[either_field::make_template(
+    GenStructs: true;
+    Named: [name: NotDisplay]
+)]
+struct Entry {
+    name: either_field::either!(impl ToString),
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[either_field::make_template(
+    FirstPlayer: [player: i32 = 1],
+    SecondPlayer: [player: i32 = 2]
+)]
+struct Score {
+    player: either_field::either!(i32 | String),
+}
+
+#[either_field::make_template(
+    GenStructs: true;
+    WithId: [player: i32],
+    WithName: [player: String]
+)]
+struct Entry {
+    #[default(0)]
+    player: either_field::either!(i32 | String),
+}
+
+fn main() {}
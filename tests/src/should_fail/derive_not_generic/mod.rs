@@ -0,0 +1,22 @@
+use either_field::EitherVariants;
+
+#[derive(EitherVariants)]
+#[variants(ScoreWithId: [player: u64])]
+struct Score {
+    #[either(i32, u64)]
+    player: i32,
+    value: i32,
+}
+
+// traits are only implemented for generated structs
+#[derive(EitherVariants)]
+#[variants(
+    GenTraits: true;
+    EntryWithId: [player: u64]
+)]
+struct Entry<P> {
+    #[either(i32, u64)]
+    player: P,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+either_field::extend_template! {
+    either_field_tests::exported::Entry =>
+    GenViews: true;
+    NamedEntry: [key: String]
+}
+
+either_field::extend_template! {
+    either_field_tests::exported::Score =>
+    ScoreWithFloat: [player: f64]
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[either_field::make_template(
+    Naming: "{Template}With{Field}";
+    _: [player: u64],
+    _: [player: String]
+)]
+struct Score {
+    player: either_field::either!(u64 | String),
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#[either_field::make_template(
+    RequireAllFields: true;
+    ScoreWithPlayer: [
+        player: i32
+    ]
+)]
+struct Score {
+    player: either_field::either!(i32 | u32),
+    value: either_field::either!(() | i32),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#[either_field::make_template(
+    ScoreWithPlayer: [
+        player: String
+    ]
+)]
+struct Score {
+    player: either_field::either!(i32 | String),
+}
+
+#[either_field::template_impl(Score: [ScoreWithName])]
+impl Score {
+    fn player(&self) -> &either_field::either!(player) {
+        &self.player
+    }
+}
+
+fn main() {}
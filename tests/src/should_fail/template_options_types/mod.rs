@@ -0,0 +1,10 @@
+#[either_field::make_template(
+    HideTemplate: true;
+    ScoreWithId: [player: u64]
+)]
+struct Score {
+    player: either_field::either!(u64 | String),
+    value: i32,
+}
+
+fn main() {}
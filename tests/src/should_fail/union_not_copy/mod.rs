@@ -0,0 +1,11 @@
+#[either_field::make_template(
+    GenStructs: true;
+    Owned: [
+        value: String
+    ]
+)]
+union Text {
+    value: either_field::either!(u64 | String),
+}
+
+fn main() {}
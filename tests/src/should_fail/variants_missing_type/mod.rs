@@ -0,0 +1,18 @@
+mod proto {
+    pub struct Score<P, V> {
+        pub player: P,
+        pub value: V,
+    }
+}
+
+either_field::variants! {
+    type proto::Score<player, value = i32>
+    => ScoreWithDefaults: [value: _]
+}
+
+either_field::variants! {
+    type proto::Score<player, value = i32>
+    => ScoreWithRank: [player: u64, rank: u8]
+}
+
+fn main() {}
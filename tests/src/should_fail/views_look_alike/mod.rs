@@ -0,0 +1,23 @@
+mod m {
+    // not the real `PhantomData`, which views mustn't be generated for
+    #[repr(C)]
+    pub struct PhantomData<T>(pub T);
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    GenViews: true;
+    Byte: [x: m::PhantomData<u8>],
+    Flag: [x: m::PhantomData<bool>]
+)]
+#[repr(C)]
+struct Cell {
+    x: either_field::either!(m::PhantomData<u8> | m::PhantomData<bool>),
+}
+
+fn main() {
+    let byte = Byte {
+        x: m::PhantomData(7),
+    };
+    let _flag: &Flag = byte.as_flag();
+}
@@ -0,0 +1,116 @@
+use either_field::{Absent, make_template};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PlayerData {
+    name: String,
+}
+
+#[make_template(
+    GenStructs: true,
+    OmitAbsentFields: true;
+    Anonymous: [player: Absent],
+    Named: [player: PlayerData]
+)]
+#[derive(Debug)]
+struct Score {
+    player: either_field::either!(Absent | PlayerData),
+    // a field which really is `()`, which isn't omitted
+    marker: either_field::either!(() | u8),
+    #[default(0)]
+    value: i32,
+}
+
+#[make_template(
+    GenStructs: true,
+    OmitAbsentFields: true;
+    Unlabelled: [Absent],
+    Labelled: [String]
+)]
+struct Measure(either_field::either!(Absent | String), f64);
+
+#[make_template(
+    WithoutId: [id: Absent],
+    WithId: [id: u64]
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: either_field::either!(Absent | u64),
+    name: String,
+}
+
+// `Absent` fields which aren't omitted are skipped by serde
+#[make_template(
+    GenStructs: true;
+    Guest: [account: Absent],
+    Member: [account: u32]
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Visitor {
+    account: either_field::either!(Absent | u32),
+    name: String,
+}
+
+mod mine {
+    // not `either_field::Absent`, so it's neither omitted nor skipped
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct Absent(pub u8);
+}
+
+#[make_template(
+    GenStructs: true,
+    OmitAbsentFields: true;
+    Missing: [data: either_field::Absent],
+    Own: [data: mine::Absent]
+)]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Payload {
+    data: either_field::either!(either_field::Absent | mine::Absent),
+    tag: u8,
+}
+
+fn main() {
+    let anonymous = Anonymous::new();
+    assert_eq!(anonymous.marker, ());
+    assert_eq!(anonymous.value, 0);
+    let named = Named::new(PlayerData {
+        name: "p".to_string(),
+    });
+    assert_eq!(named.player.name, "p");
+
+    let unlabelled = Unlabelled(1.0);
+    let labelled = Labelled("m".to_string(), unlabelled.0);
+    assert_eq!(labelled.1, 1.0);
+
+    let without_id: WithoutId = Record {
+        id: Absent,
+        name: "r".to_string(),
+    };
+    let json = serde_json::to_string(&without_id).unwrap();
+    assert_eq!(json, r#"{"name":"r"}"#);
+    let parsed: WithoutId = serde_json::from_str(r#"{"name":"r"}"#).unwrap();
+    assert_eq!(parsed, without_id);
+    let with_id: WithId = serde_json::from_str(r#"{"id":3,"name":"r"}"#).unwrap();
+    assert_eq!(with_id.id, 3);
+    assert_eq!(serde_json::to_string(&with_id).unwrap(), r#"{"id":3,"name":"r"}"#);
+
+    let guest = Guest {
+        account: Absent,
+        name: "g".to_string(),
+    };
+    assert_eq!(serde_json::to_string(&guest).unwrap(), r#"{"name":"g"}"#);
+    assert_eq!(serde_json::from_str::<Guest>(r#"{"name":"g"}"#).unwrap(), guest);
+    let member = Member {
+        account: 1,
+        name: "m".to_string(),
+    };
+    assert_eq!(serde_json::to_string(&member).unwrap(), r#"{"account":1,"name":"m"}"#);
+    assert_eq!(format!("{:?}", Absent), "Absent");
+
+    let own = Own {
+        data: mine::Absent(5),
+        tag: 1,
+    };
+    assert_eq!(serde_json::to_string(&own).unwrap(), r#"{"data":5,"tag":1}"#);
+    assert_eq!(serde_json::to_string(&Missing { tag: 1 }).unwrap(), r#"{"tag":1}"#);
+}
@@ -0,0 +1,105 @@
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerData {
+    pub name: String,
+}
+
+mod scores {
+    use super::PlayerData;
+
+    #[either_field::make_template(
+        GenAccessors: true;
+        pub ScoreWithId: [player: u64],
+        pub ScoreWithPlayer: [player: PlayerData]
+    )]
+    pub struct Score<T> {
+        pub player: either_field::either!(u64 | PlayerData),
+        pub value: T,
+    }
+}
+
+use scores::{Score, ScoreHasPlayer as _, ScoreWithId, ScoreWithPlayer};
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    GenAccessors: true;
+    Anonymous: [player: ()],
+    Named: [player: String, r#type: u8]
+)]
+struct Entry {
+    value: i32,
+    player: either_field::either!(() | String),
+    r#type: either_field::either!(i8 | u8),
+}
+
+// in the same module as `Entry`, with a field of the same name
+#[either_field::make_template(
+    GenStructs: true,
+    GenAccessors: true;
+    SoloMatch: [player: String],
+    TeamMatch: [player: Vec<String>]
+)]
+struct Match {
+    player: either_field::either!(String | Vec<String>),
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    GenAccessors: true;
+    Unsigned: [1: u32],
+    Signed: [1: i32]
+)]
+struct Wrapper(bool, either_field::either!(u32 | i32));
+
+fn print_player<T: EntryHasPlayer>(t: &T) -> String
+where
+    T::Player: Debug,
+{
+    format!("{:?}", t.get_player())
+}
+
+fn score_player<T: scores::ScoreHasPlayer<Player = u64>>(t: &T) -> u64 {
+    *t.get_player()
+}
+
+fn main() {
+    let with_id: ScoreWithId<f32> = Score {
+        player: 1,
+        value: 0.5,
+    };
+    let mut with_player: ScoreWithPlayer<f32> = Score {
+        player: PlayerData {
+            name: "p".to_string(),
+        },
+        value: with_id.value,
+    };
+    assert_eq!(score_player(&with_id), 1);
+    with_player.get_player_mut().name = "q".to_string();
+    assert_eq!(with_player.into_player().name, "q");
+
+    let mut named = Named {
+        value: 1,
+        player: "n".to_string(),
+        r#type: 2,
+    };
+    *named.get_type_mut() += 1;
+    assert_eq!(print_player(&named), "\"n\"");
+    let anonymous = Anonymous { value: 1, r#type: -1 };
+    assert_eq!(*anonymous.get_type() + 1, 0);
+    assert_eq!(named.into_type(), 3);
+
+    let team = TeamMatch {
+        player: vec!["t".to_string()],
+    };
+    assert_eq!(team.get_player().len(), 1);
+    let solo = SoloMatch {
+        player: "s".to_string(),
+    };
+    assert_eq!(MatchHasPlayer::get_player(&solo), "s");
+
+    let signed = Signed(true, -4);
+    assert_eq!(*signed.get_1(), -4);
+    assert_eq!(WrapperHasField1::into_1(Unsigned(false, 4)), 4);
+}
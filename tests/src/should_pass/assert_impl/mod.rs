@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+#[either_field::make_template(
+    AssertImpl: [Send, Sync, 'static];
+    ScoreWithId: [player: u64],
+    ScoreWithName: [player: Arc<str>]
+)]
+struct Score<T>
+where
+    T: Send + Sync + 'static,
+{
+    player: either_field::either!(u64 | Arc<str>),
+    value: T,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    AssertImpl: [Send, Clone, std::fmt::Debug];
+    Anonymous: [player: ()],
+    Named: [player: String]
+)]
+#[derive(Clone, Debug)]
+struct Entry {
+    player: either_field::either!(() | String),
+    value: i32,
+}
+
+fn spawn<T: Send + 'static>(value: T) -> T {
+    std::thread::spawn(move || value).join().unwrap()
+}
+
+fn main() {
+    let with_name: ScoreWithName<f32> = Score {
+        player: Arc::from("p"),
+        value: 1.0,
+    };
+    assert_eq!(&*spawn(with_name).player, "p");
+    let named = Named {
+        player: "n".to_string(),
+        value: 1,
+    };
+    assert_eq!(spawn(named.clone()).player, named.player);
+    assert_eq!(Anonymous { value: 2 }.value, 2);
+}
@@ -0,0 +1,57 @@
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    AssertLayout: true;
+    IntPacket: [
+        payload: u32
+    ],
+    FloatPacket: [
+        payload: f32
+    ],
+    MarkedPacket: [
+        marker: std::marker::PhantomData<u8>
+    ]
+)]
+#[repr(C)]
+struct Packet {
+    header: u16,
+    marker: either_field::either!(() | std::marker::PhantomData<u8>),
+    payload: either_field::either!(u32 | f32),
+    trailer: u8,
+}
+
+#[either_field::make_template(
+    AssertLayout: true;
+    Ints: [u32, _],
+    Floats: [f32, _],
+)]
+#[repr(C, align(8))]
+struct Pair(either_field::either!(u32 | f32), u32);
+
+fn main() {
+    let int = IntPacket {
+        header: 1,
+        payload: 2,
+        trailer: 3,
+    };
+    let float = FloatPacket {
+        header: 1,
+        payload: 2.0,
+        trailer: 3,
+    };
+    let marked = MarkedPacket {
+        header: 1,
+        marker: std::marker::PhantomData,
+        payload: 2,
+        trailer: 3,
+    };
+    assert_eq!(int.header + float.header + marked.header, 3);
+    assert_eq!(int.payload + marked.payload, 4);
+    assert_eq!(float.payload, 2.0);
+    assert_eq!(int.trailer + float.trailer + marked.trailer, 9);
+
+    let ints: Ints = Pair(1, 2);
+    let floats: Floats = Pair(1.0, 2);
+    assert_eq!(ints.1, floats.1);
+    assert_eq!(ints.0 as f32, floats.0);
+}
@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+#[either_field::make_template(
+    GenTransitions: true,
+    GenMaps: true;
+    Labelled: [label: String],
+    Numbered: [label: u32, note: &'static str]
+)]
+struct Reading {
+    label: either_field::either!(impl Display),
+    note: either_field::either!(() | impl Into<String>),
+    value: f64,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    DeleteTemplate: true,
+    GenTransitions: true;
+    Named: [name: String],
+    Numbered2: [name: u64],
+    WithExtra: [name: u64, extra: Vec<u8>]
+)]
+struct Entry {
+    name: either_field::either!(impl ToString),
+    extra: either_field::either!(default () | impl AsRef<[u8]>),
+}
+
+#[either_field::make_template(
+    GenStructs: true;
+    Plain: [tag: ()],
+    Tagged: [tag: &'static str]
+)]
+struct Tag {
+    tag: either_field::either!(() | impl Display),
+}
+
+fn describe<L: Display, N>(reading: &Reading<L, N>) -> String {
+    format!("{}: {}", reading.label, reading.value)
+}
+
+fn main() {
+    let labelled: Labelled = Reading {
+        label: "temp".to_string(),
+        note: (),
+        value: 1.5,
+    };
+    assert_eq!(describe(&labelled), "temp: 1.5");
+    let numbered: Numbered = labelled.with_label(3).with_note("n");
+    let note: String = numbered.note.into();
+    assert_eq!(note, "n");
+    let mapped = Reading {
+        label: 1,
+        note: (),
+        value: 0.0,
+    }
+    .map_label(|label| label + 1);
+    assert_eq!(mapped.label, 2);
+
+    let named = Named {
+        name: "n".to_string(),
+        extra: (),
+    };
+    let numbered = named.with_name(4).with_extra(vec![1]);
+    assert_eq!(numbered.name.to_string(), "4");
+    assert_eq!(numbered.extra, vec![1]);
+
+    let tagged = Tagged { tag: "t" };
+    let plain = Plain { tag: () };
+    assert_eq!(format!("{}{:?}", tagged.tag, plain.tag), "t()");
+}
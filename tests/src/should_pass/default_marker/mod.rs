@@ -0,0 +1,80 @@
+#[derive(Debug, PartialEq)]
+struct PlayerData {
+    player_id: i32,
+}
+
+#[either_field::make_template(
+    ScoreWithId: [
+        player: i32
+    ],
+    ScoreWithPlayer: [
+        player: _
+    ]
+)]
+struct Score {
+    player: either_field::either!(i32 | default PlayerData),
+    value: i32,
+}
+
+#[either_field::make_template(
+    GenStructs: true;
+    OnlyValue: [
+        player: ()
+    ],
+)]
+struct StructScore {
+    player: either_field::either!(() | default PlayerData),
+    value: i32,
+}
+
+// a module named `default` isn't the marker
+mod default {
+    #[derive(Debug, PartialEq)]
+    pub struct Fallback;
+}
+
+#[either_field::make_template(
+    GenStructs: true;
+    WithFallback: [reason: default::Fallback],
+    WithCode: [reason: u8]
+)]
+struct Outcome {
+    reason: either_field::either!(u8 | default::Fallback),
+}
+
+#[derive(either_field::EitherVariants)]
+#[variants(
+    GenStructs: true;
+    DerivedFallback: [reason: default::Fallback]
+)]
+struct DerivedOutcome {
+    #[either(u8, default::Fallback)]
+    reason: u8,
+}
+
+fn main() {
+    let with_id = ScoreWithId {
+        player: 1,
+        value: 0,
+    };
+    let with_player = ScoreWithPlayer {
+        player: PlayerData { player_id: with_id.player },
+        value: with_id.value,
+    };
+    assert_eq!(with_player.player, PlayerData { player_id: 1 });
+
+    let template = StructScore {
+        player: PlayerData { player_id: 2 },
+        value: 0,
+    };
+    let only_value = OnlyValue {
+        player: (),
+        value: template.value,
+    };
+    assert_eq!(only_value.player, ());
+    assert_eq!(template.player.player_id, 2);
+
+    assert_eq!(WithFallback { reason: default::Fallback }.reason, default::Fallback);
+    assert_eq!(WithCode { reason: 1 }.reason, 1);
+    assert_eq!(DerivedFallback { reason: default::Fallback }.reason, default::Fallback);
+}
@@ -0,0 +1,62 @@
+#[derive(Debug, PartialEq)]
+struct PlayerData {
+    player_id: i32,
+}
+
+#[either_field::make_template(
+    ScoreWithId: [
+        player: i32 = 0
+    ],
+    ScoreWithPlayer: [
+        player: PlayerData
+    ]
+)]
+#[derive(Debug)]
+struct Score {
+    player: either_field::either!(i32 | PlayerData),
+    #[default(100)]
+    value: i32,
+    name_cache: either_field::either!(() | String),
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true;
+    Empty: [_],
+    Flagged: [bool = true, _],
+)]
+#[derive(Debug)]
+struct Tuple(
+    either_field::either!(() | bool),
+    #[default(String::from("text"))] String,
+);
+
+// the derived `Default` is kept instead of the generated one
+#[either_field::make_template(
+    GenStructs: true;
+    Counter: [count: u32 = 5],
+    Label: [count: String]
+)]
+#[derive(Debug, Default)]
+struct Tally {
+    count: either_field::either!(u32 | String),
+}
+
+fn main() {
+    let with_id = ScoreWithId::default();
+    assert_eq!((with_id.player, with_id.value), (0, 100));
+
+    let with_player = ScoreWithPlayer::new(PlayerData { player_id: 1 });
+    assert_eq!(with_player.player, PlayerData { player_id: 1 });
+    assert_eq!(with_player.value, 100);
+
+    let empty = Empty::default();
+    assert_eq!(empty.0, "text");
+    let flagged = Flagged::new();
+    assert!(flagged.0);
+    assert_eq!(flagged.1, "text");
+
+    assert_eq!(Counter::new().count, 5);
+    assert_eq!(Counter::default().count, 0);
+    assert_eq!(Label::new("l".to_string()).count, "l");
+}
@@ -0,0 +1,83 @@
+use either_field::EitherVariants;
+
+#[derive(Debug, Clone, PartialEq)]
+struct PlayerData {
+    name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, EitherVariants)]
+#[variants(
+    GenStructs: true,
+    GenTransitions: true;
+    ScoreWithId: [player: u64],
+    ScoreWithPlayer: [player: PlayerData]
+)]
+#[variant_attr(derive(Debug))]
+struct Score {
+    #[either(u64, PlayerData)]
+    player: u64,
+    #[default(0)]
+    value: i32,
+}
+
+#[derive(Debug, EitherVariants)]
+#[variants(
+    Plain: [value: i32],
+    Labelled: [label: String, value: f64]
+)]
+struct Reading<'a, L, V> {
+    #[either((), default String)]
+    label: L,
+    #[either(default i32, f64)]
+    value: V,
+    source: &'a str,
+}
+
+#[derive(EitherVariants)]
+#[variants(
+    GenStructs: true;
+    Unsigned: [u32],
+    Signed: [i32]
+)]
+struct Wrapper(#[either(u32, i32)] u32, bool);
+
+#[either_field::template_impl(Score)]
+impl Score {
+    fn player(&self) -> &either_field::either!(player) {
+        &self.player
+    }
+}
+
+fn main() {
+    let with_id = ScoreWithId::new(4);
+    let with_player: ScoreWithPlayer = with_id.with_player(PlayerData {
+        name: "p".to_string(),
+    });
+    assert_eq!(with_player.player().name, "p");
+    assert_eq!(with_player.value, 0);
+    let template = Score {
+        player: 1,
+        value: 2,
+    };
+    assert_eq!(template.player, 1);
+    assert_eq!(
+        format!("{:?}", with_player),
+        r#"ScoreWithPlayer { player: PlayerData { name: "p" }, value: 0 }"#
+    );
+
+    let plain: Plain<'_> = Reading {
+        label: "x".to_string(),
+        value: 1,
+        source: "sensor",
+    };
+    let labelled: Labelled<'_> = Reading {
+        label: plain.label,
+        value: 1.5,
+        source: plain.source,
+    };
+    assert_eq!(labelled.value, 1.5);
+
+    let unsigned = Unsigned(1, true);
+    let signed = Signed(-1, unsigned.1);
+    assert!(signed.1);
+}
@@ -0,0 +1,109 @@
+//! Every derived struct gets documented
+#![deny(missing_docs)]
+
+/// The data of a player
+pub struct PlayerData {
+    /// The id of the player
+    pub player_id: u64,
+}
+
+/// A score
+#[either_field::make_template(
+    /// A score of a player we only know the id of
+    pub ScoreWithId: [player: u64],
+    pub ScoreWithPlayer: [player: PlayerData]
+)]
+pub struct Score {
+    /// Who got the score
+    pub player: either_field::either!(u64 | PlayerData),
+    /// The score
+    pub value: i32,
+}
+
+/// An entry
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true;
+    /// An entry nobody claimed
+    pub Anonymous: [player: ()],
+    pub Named: [player: String]
+)]
+pub struct Entry {
+    /// Who made the entry
+    pub player: either_field::either!(() | String),
+    /// What was entered
+    pub value: i32,
+}
+
+/// A reading, with everything that generates public items
+#[either_field::make_template(
+    GenStructs: true,
+    GenTransitions: true,
+    GenAccessors: true,
+    GenViews: true,
+    Module: pub reading_variants,
+    Reexport: [pub Raw, pub Marked];
+    pub Raw: [mark: ()],
+    pub Marked: [mark: ::core::marker::PhantomData<u8>],
+    pub Scaled: [mark: f32]
+)]
+#[repr(C)]
+pub struct Reading {
+    /// What the reading is marked with
+    pub mark: either_field::either!(() | ::core::marker::PhantomData<u8> | f32),
+    /// The value read
+    #[default(0)]
+    pub value: i32,
+}
+
+/// A measure, generating type declarations
+#[either_field::make_template(
+    GenTransitions: true,
+    GenMaps: true,
+    GenAccessors: true;
+    pub Exact: [unit: u8],
+    pub Rough: [unit: String]
+)]
+pub struct Measure {
+    /// The unit of the measure
+    pub unit: either_field::either!(u8 | String),
+    /// The amount measured
+    pub amount: i32,
+}
+
+/// Something that isn't a template
+pub struct Pair<A, B> {
+    /// The first one
+    pub first: A,
+    /// The second one
+    pub second: B,
+}
+
+either_field::variants!(
+    type Pair<first, second = u8> =>
+    /// A pair of a string and a byte
+    pub NamedPair: [first: String]
+);
+
+fn main() {
+    let with_id: ScoreWithId = Score {
+        player: 1,
+        value: 10,
+    };
+    let anonymous = Anonymous { value: with_id.value };
+    let named = Named {
+        player: "n".to_string(),
+        value: anonymous.value,
+    };
+    assert_eq!(named.value, 10);
+
+    let raw = Raw::new();
+    assert_eq!(raw.as_marked().value, 0);
+    let scaled = raw.with_mark(1.5);
+    assert_eq!(*reading_variants::ReadingHasMark::get_mark(&scaled), 1.5);
+
+    let exact: Exact = Measure { unit: 1, amount: 2 };
+    let rough: Rough = exact.map_unit(|unit| unit.to_string());
+    assert_eq!(MeasureHasUnit::into_unit(rough), "1");
+
+}
@@ -0,0 +1,34 @@
+use either_field_tests::exported::{RawEntry, Score, ScoreWithId};
+
+either_field::extend_template! {
+    either_field_tests::exported::Score =>
+    ScoreWithName: [player: String],
+    ScoreWithBoth: [player: (u64, String)]
+}
+
+either_field::extend_template! {
+    either_field_tests::exported::Entry =>
+    RequireAllFields: true;
+    NamedEntry: [key: String]
+}
+
+fn main() {
+    let with_id: ScoreWithId<f32> = Score {
+        player: 7,
+        value: 0.5,
+    };
+    let with_name: ScoreWithName<f32> = Score {
+        player: "seven".to_string(),
+        value: with_id.value,
+    };
+    let with_both: ScoreWithBoth<f32> = Score {
+        player: (with_id.player, with_name.player),
+        value: with_name.value,
+    };
+    assert_eq!(with_both.player.0, 7);
+
+    let raw = RawEntry::new(3);
+    let named: NamedEntry = NamedEntry::new("three".to_string());
+    assert_eq!(raw.hits, named.hits);
+    assert_eq!(named.key, "three");
+}
@@ -0,0 +1,35 @@
+#[derive(Debug, PartialEq)]
+struct A;
+
+#[derive(Debug, PartialEq)]
+struct B(u8);
+
+#[either_field::make_template(
+    WithValue: [
+        value: i32
+    ]
+)]
+#[derive(Debug)]
+struct Template<T> {
+    marker: A,
+    generic: T,
+    wrapped: either_field::either!(() | B),
+    value: either_field::either!(() | i32),
+}
+
+fn main() {
+    let x: WithValue<u8> = Template {
+        marker: A,
+        generic: 0u8,
+        wrapped: (),
+        value: 1,
+    };
+    assert_eq!(x.marker, A);
+    let y: Template<u8, B, ()> = Template {
+        marker: A,
+        generic: 0u8,
+        wrapped: B(2),
+        value: (),
+    };
+    assert_eq!(y.wrapped, B(2));
+}
@@ -0,0 +1,5 @@
+include!("../../../../example/src/latest_feature.rs");
+
+fn main() {
+    test();
+}
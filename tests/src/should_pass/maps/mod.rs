@@ -0,0 +1,83 @@
+#[derive(Debug, PartialEq)]
+struct PlayerData {
+    player_id: i32,
+    player_name: String,
+}
+
+fn lookup(player_id: i32) -> PlayerData {
+    PlayerData {
+        player_id,
+        player_name: format!("Player {player_id}"),
+    }
+}
+
+#[either_field::make_template(
+    GenMaps: true;
+    ScoreWithId: [
+        player: i32
+    ],
+    ScoreWithPlayer: [
+        player: PlayerData
+    ]
+)]
+#[derive(Debug)]
+struct Score {
+    player: either_field::either!(i32 | PlayerData),
+    rank: either_field::either!(() | u32),
+    value: i32,
+}
+
+#[derive(Debug, PartialEq)]
+struct NotFound(i32);
+
+fn find(player_id: i32) -> Result<PlayerData, NotFound> {
+    match player_id > 0 {
+        true => Ok(lookup(player_id)),
+        false => Err(NotFound(player_id)),
+    }
+}
+
+async fn fetch(player_id: i32) -> Result<PlayerData, NotFound> {
+    find(player_id)
+}
+
+// the futures here never wait on anything, so polling them once is enough
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+    match std::pin::pin!(future).poll(&mut context) {
+        std::task::Poll::Ready(output) => output,
+        std::task::Poll::Pending => unreachable!(),
+    }
+}
+
+fn main() {
+    let with_id: ScoreWithId = Score {
+        player: 1,
+        rank: (),
+        value: 10,
+    };
+    let with_player: ScoreWithPlayer = with_id.map_player(lookup);
+    assert_eq!(with_player.player.player_name, "Player 1");
+
+    let ranked = with_player.map_rank(|()| 3u32);
+    assert_eq!((ranked.rank, ranked.value), (3, 10));
+
+    let score = |player| -> ScoreWithId {
+        Score {
+            player,
+            rank: (),
+            value: 10,
+        }
+    };
+    let found: Result<ScoreWithPlayer, _> = score(2).try_map_player(find);
+    assert_eq!(found.unwrap().player.player_id, 2);
+    assert_eq!(score(-1).try_map_player(find).unwrap_err(), NotFound(-1));
+
+    let fetched: ScoreWithPlayer = block_on(score(3).map_player_async(|id| async move {
+        lookup(id)
+    }));
+    assert_eq!(fetched.player.player_id, 3);
+    let fetched = block_on(score(4).try_map_player_async(fetch));
+    assert_eq!(fetched.unwrap().player.player_name, "Player 4");
+    assert!(block_on(score(0).try_map_player_async(fetch)).is_err());
+}
@@ -0,0 +1,89 @@
+use either_field::{EitherVariants, template_impl};
+
+struct PlayerData {
+    player_id: u64,
+}
+
+#[either_field::make_template(
+    Module: pub score_variants,
+    Reexport: [ScoreWithId, pub(crate) ScoreHasPlayer],
+    GenAccessors: true;
+    ScoreWithId: [player: u64],
+    ScoreWithPlayer: [player: PlayerData]
+)]
+struct Score {
+    player: either_field::either!(u64 | PlayerData),
+    value: i32,
+}
+
+#[template_impl(Score: [ScoreWithPlayer])]
+impl Score {
+    fn player_id(&self) -> u64 {
+        self.player.player_id
+    }
+}
+
+mod entries {
+    #[either_field::make_template(
+        GenStructs: true,
+        OmitEmptyTupleFields: true,
+        Module: entry_variants,
+        Reexport: [pub Named];
+        pub Anonymous: [player: ()],
+        pub Named: [player: String]
+    )]
+    pub struct Entry {
+        pub player: either_field::either!(() | String),
+        value: i32,
+    }
+
+    pub fn anonymous(value: i32) -> entry_variants::Anonymous {
+        entry_variants::Anonymous { value }
+    }
+
+    pub fn named(player: &str) -> Named {
+        Named {
+            player: player.to_string(),
+            value: 0,
+        }
+    }
+
+    pub fn value(anonymous: &entry_variants::Anonymous) -> i32 {
+        anonymous.value
+    }
+}
+
+#[derive(EitherVariants)]
+#[variants(
+    Module: pair_variants;
+    BytePair: [first: u8]
+)]
+struct Pair<A> {
+    #[either(u8, u16)]
+    first: A,
+    second: i32,
+}
+
+fn main() {
+    let with_id: ScoreWithId = Score {
+        player: 1,
+        value: 10,
+    };
+    let with_player: score_variants::ScoreWithPlayer = Score {
+        player: PlayerData {
+            player_id: *with_id.get_player(),
+        },
+        value: with_id.value,
+    };
+    assert_eq!(with_player.player_id(), 1);
+
+    assert_eq!(entries::value(&entries::anonymous(2)), 2);
+    let named: entries::Named = entries::named("n");
+    assert_eq!(named.player, "n");
+
+    let pair: pair_variants::BytePair = Pair {
+        first: 1,
+        second: 2,
+    };
+    assert_eq!(pair.first, 1);
+}
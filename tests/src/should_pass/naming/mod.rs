@@ -0,0 +1,77 @@
+use either_field::template_impl;
+
+pub struct PlayerData {
+    pub player_id: u64,
+}
+
+#[either_field::make_template(
+    Naming: "{Template}With{Field}";
+    _: [player: PlayerData],
+    _: [player: PlayerData, rank: u32],
+    ScoreWithId: [player: u64]
+)]
+struct Score {
+    player: either_field::either!(u64 | PlayerData),
+    rank: either_field::either!(() | u32),
+    value: i32,
+}
+
+#[template_impl(Score: [ScoreWithPlayerAndRank])]
+impl Score {
+    fn rank(&self) -> u32 {
+        self.rank
+    }
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    Naming: "{Type}Entry";
+    _: [player: ()],
+    _: [player: Vec<String>],
+    _: [player: &'static str]
+)]
+struct Entry {
+    player: either_field::either!(() | Vec<String> | &'static str),
+    value: i32,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    Naming: "{Template}Of{Type}";
+    _: [u8],
+    _: [Option<u64>]
+)]
+struct Pair(either_field::either!(u8 | Option<u64>), i32);
+
+fn main() {
+    let with_player: ScoreWithPlayer = Score {
+        player: PlayerData { player_id: 1 },
+        rank: (),
+        value: 10,
+    };
+    let ranked: ScoreWithPlayerAndRank = Score {
+        player: with_player.player,
+        rank: 2,
+        value: with_player.value,
+    };
+    assert_eq!(ranked.rank(), 2);
+    let with_id: ScoreWithId = Score {
+        player: ranked.player.player_id,
+        rank: (),
+        value: 0,
+    };
+    assert_eq!(with_id.player, 1);
+
+    let _ = UnitEntry { value: 1 };
+    let _ = VecStringEntry {
+        player: vec![],
+        value: 1,
+    };
+    let _ = StrEntry {
+        player: "p",
+        value: 1,
+    };
+    let _ = PairOfU8(1, 2);
+    let _ = PairOfOptionU64(None, 2);
+}
@@ -0,0 +1,46 @@
+#[either_field::make_template(
+    Report: true;
+    ReportedWithId: [player: u64],
+    ReportedWithName: [player: String]
+)]
+struct Reported<T> {
+    player: either_field::either!(u64 | String),
+    value: T,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    Report: true;
+    ReportedAnonymous: [player: ()],
+    ReportedNamed: [player: Vec<String>]
+)]
+struct ReportedEntry {
+    player: either_field::either!(() | Vec<String>),
+    value: i32,
+}
+
+#[derive(either_field::EitherVariants)]
+#[variants(
+    Report: true;
+    ReportedPairOfBytes: [first: u8]
+)]
+struct ReportedPair<A> {
+    #[either(u16, u8)]
+    first: A,
+    second: i32,
+}
+
+fn main() {
+    let with_id: ReportedWithId<f32> = Reported {
+        player: 1,
+        value: 1.0,
+    };
+    assert_eq!(with_id.player, 1);
+    assert_eq!(ReportedAnonymous { value: 2 }.value, 2);
+    let pair: ReportedPairOfBytes = ReportedPair {
+        first: 1,
+        second: 2,
+    };
+    assert_eq!(pair.first, 1);
+}
@@ -0,0 +1,86 @@
+use either_field::template_impl;
+
+#[derive(Debug, PartialEq)]
+struct PlayerData {
+    player_id: i32,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true;
+    ScoreWithId: [
+        player: i32
+    ],
+    ScoreWithPlayer: [
+        player: PlayerData
+    ],
+    ScoreWithoutPlayer: [
+        player: ()
+    ]
+)]
+struct Score {
+    player: either_field::either!(i32 | PlayerData | ()),
+    value: i32,
+}
+
+#[template_impl(Score)]
+impl Score {
+    fn doubled(&self) -> i32 {
+        self.value * 2
+    }
+}
+
+#[template_impl(Score: [ScoreWithId, ScoreWithPlayer])]
+impl Score {
+    fn player(&self) -> &either_field::either!(player) {
+        &self.player
+    }
+}
+
+#[either_field::make_template(
+    Pair: [
+        0: i32,
+        1: i32
+    ],
+    Single: [
+        0: i32
+    ],
+    Same: [
+        0: i32
+    ]
+)]
+struct Tuple<T>(either_field::either!(() | i32), either_field::either!(() | i32), T);
+
+#[template_impl(Tuple)]
+impl<T: Clone> Tuple<T> {
+    fn first(&self) -> either_field::either!(0) {
+        self.0
+    }
+
+    fn last(&self) -> T {
+        self.2.clone()
+    }
+}
+
+fn main() {
+    let with_id = ScoreWithId {
+        player: 1,
+        value: 2,
+    };
+    let with_player = ScoreWithPlayer {
+        player: PlayerData { player_id: 1 },
+        value: 3,
+    };
+    let without_player = ScoreWithoutPlayer { value: 4 };
+    assert_eq!(*with_id.player(), 1);
+    assert_eq!(with_player.player(), &PlayerData { player_id: 1 });
+    assert_eq!(
+        with_id.doubled() + with_player.doubled() + without_player.doubled(),
+        18
+    );
+
+    let pair: Pair<&str> = Tuple(1, 2, "pair");
+    let single: Single<&str> = Tuple(3, (), "single");
+    assert_eq!(pair.first() + single.first(), 4);
+    assert_eq!(pair.last(), "pair");
+}
@@ -0,0 +1,61 @@
+use either_field::VariantOf;
+
+mod entries {
+    #[either_field::make_template(
+        GenStructs: true,
+        GenTraits: true,
+        HideTemplate: true,
+        TemplateVis: pub(crate),
+        RenameTemplate: RawEntry;
+        pub Named: [player: String],
+        pub WithId: [player: u64]
+    )]
+    #[derive(Debug)]
+    pub struct Entry {
+        pub player: either_field::either!(u64 | String),
+        pub value: i32,
+    }
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    TemplateVis: pub(crate),
+    RenameTemplate: ScoreTemplate,
+    Module: score_variants,
+    Reexport: [ScoreWithName];
+    ScoreWithName: [player: String],
+    ScoreWithId: [player: u64]
+)]
+pub struct Score {
+    player: either_field::either!(u64 | String),
+    value: i32,
+}
+
+fn is_variant_of<T: VariantOf<entries::RawEntry>>(_: &T) {}
+
+fn main() {
+    let raw = entries::RawEntry {
+        player: 1,
+        value: 2,
+    };
+    assert_eq!(raw.player, 1);
+    let named = entries::Named {
+        player: "n".to_string(),
+        value: raw.value,
+    };
+    is_variant_of(&named);
+
+    let template = ScoreTemplate {
+        player: 1,
+        value: 2,
+    };
+    let with_name = ScoreWithName {
+        player: template.player.to_string(),
+        value: template.value,
+    };
+    let with_id = score_variants::ScoreWithId {
+        player: 1,
+        value: with_name.value,
+    };
+    assert_eq!(with_id.player, 1);
+}
@@ -0,0 +1,76 @@
+use either_field::{HasField, Template, VariantOf, field_id, make_template};
+
+#[derive(Debug, Clone, PartialEq)]
+struct PlayerData {
+    name: String,
+}
+
+#[make_template(
+    GenTraits: true;
+    ScoreWithId: [player: u64],
+    ScoreWithPlayer: [player: PlayerData]
+)]
+struct Score<T> {
+    player: either_field::either!(u64 | PlayerData),
+    value: T,
+}
+
+#[make_template(
+    GenStructs: true,
+    GenTraits: true,
+    OmitEmptyTupleFields: true;
+    Anonymous: [player: ()],
+    Named: [player: String]
+)]
+struct Entry {
+    player: either_field::either!(() | String),
+    value: i32,
+}
+
+#[make_template(
+    GenStructs: true,
+    GenTraits: true;
+    Unsigned: [u32],
+    Signed: [i32]
+)]
+struct Wrapper(either_field::either!(u32 | i32), bool);
+
+fn values<S: VariantOf<Entry> + HasField<{ field_id("value") }, Type = i32>>(entries: &[S]) -> i32 {
+    entries.iter().map(|entry| *entry.field()).sum()
+}
+
+fn rename<S: HasField<{ field_id("player") }, Type = String>>(entry: &mut S) {
+    *entry.field_mut() = "renamed".to_string();
+}
+
+fn is_score<S: VariantOf<Score<f32, u64>>>(_: &S) -> bool {
+    true
+}
+
+fn variants<T: Template>() -> &'static str {
+    std::any::type_name::<T::Variants>()
+}
+
+fn main() {
+    let with_id: ScoreWithId<f32> = Score {
+        player: 1,
+        value: 0.5,
+    };
+    assert!(is_score(&with_id));
+    assert_eq!(*HasField::<{ field_id("player") }>::field(&with_id), 1);
+    assert!(variants::<Score<f32, u64>>().contains("PlayerData"));
+
+    let mut named = Named {
+        player: "p".to_string(),
+        value: 2,
+    };
+    rename(&mut named);
+    assert_eq!(named.player, "renamed");
+    assert_eq!(values(&[Anonymous { value: 1 }, Anonymous { value: 3 }]), 4);
+    assert_eq!(values(&[named]), 2);
+    assert!(variants::<Entry>().contains("Anonymous"));
+
+    let signed = Signed(-1, true);
+    assert_eq!(*HasField::<{ field_id("0") }>::field(&signed), -1);
+    assert!(*HasField::<{ field_id("1") }>::field(&signed));
+}
@@ -0,0 +1,60 @@
+use either_field::{HasField, Template, VariantOf, field_id, make_template};
+
+#[make_template(
+    GenStructs: true,
+    GenTraits: true;
+    PackedSmall: [id: u8],
+    PackedLarge: [id: u64]
+)]
+#[repr(C, packed)]
+struct Packed {
+    id: either_field::either!(u8 | u64),
+    value: u16,
+}
+
+#[make_template(
+    GenTraits: true;
+    PackedAliasSmall: [id: u8],
+    PackedAliasLarge: [id: u64]
+)]
+#[repr(packed)]
+struct PackedAlias {
+    id: either_field::either!(u8 | u64),
+    value: u16,
+}
+
+#[make_template(
+    GenStructs: true,
+    GenTraits: true;
+    ShortHeader: [id: u8],
+    LongHeader: [id: u64]
+)]
+struct Header {
+    id: either_field::either!(u8 | u64),
+    data: [u8],
+}
+
+fn is_variant_of<T: ?Sized + VariantOf<Header>>(_: &T) -> bool {
+    true
+}
+
+fn data<S: ?Sized + HasField<{ field_id("data") }, Type = [u8]>>(header: &S) -> usize {
+    header.field().len()
+}
+
+fn variants<T: ?Sized + Template>() -> &'static str {
+    std::any::type_name::<T::Variants>()
+}
+
+fn main() {
+    let small = PackedSmall { id: 1, value: 2 };
+    let value = small.value;
+    assert_eq!(value, 2);
+    assert!(variants::<Packed>().contains("PackedLarge"));
+    assert!(variants::<PackedAlias<u8>>().contains("PackedAlias"));
+
+    // unsized structs can't be built directly, but the traits are implemented
+    let _: fn(&ShortHeader) -> usize = data::<ShortHeader>;
+    let _: fn(&LongHeader) -> bool = is_variant_of::<LongHeader>;
+    assert!(variants::<Header>().contains("ShortHeader"));
+}
@@ -0,0 +1,84 @@
+#[derive(Debug, PartialEq)]
+struct PlayerData {
+    player_id: i32,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    GenTransitions: true;
+    Raw: [
+        player: i32,
+        rank: ()
+    ],
+    Resolved: [
+        player: PlayerData,
+        rank: ()
+    ],
+    Enriched: [
+        player: PlayerData,
+        rank: u32
+    ],
+    Anonymous: [
+        player: (),
+        rank: ()
+    ]
+)]
+#[derive(Debug)]
+struct Score {
+    player: either_field::either!(i32 | PlayerData | ()),
+    rank: either_field::either!(() | u32),
+    value: i32,
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    GenTransitions: true;
+    Empty: [(), _],
+    Full: [u8, _],
+)]
+struct Tuple(either_field::either!(() | u8), &'static str);
+
+#[either_field::make_template(
+    GenTransitions: true;
+    Unresolved: [
+        player: i32
+    ],
+    WithPlayer: [
+        player: PlayerData
+    ]
+)]
+struct GenericScore<V> {
+    player: either_field::either!(i32 | PlayerData),
+    value: V,
+}
+
+fn main() {
+    let raw = Raw {
+        player: 1,
+        value: 10,
+    };
+    let resolved: Resolved = raw.with_player(PlayerData { player_id: 1 });
+    let enriched: Enriched = resolved.with_rank(3);
+    assert_eq!(enriched.player, PlayerData { player_id: 1 });
+    assert_eq!((enriched.rank, enriched.value), (3, 10));
+    let anonymous: Anonymous = Raw {
+        player: 2,
+        value: 20,
+    }
+    .with_player(());
+    assert_eq!(anonymous.value, 20);
+
+    let full: Full = Empty("text").with_0(5);
+    assert_eq!((full.0, full.1), (5, "text"));
+    let empty: Empty = full.with_0(());
+    assert_eq!(empty.0, "text");
+
+    let unresolved: Unresolved<&str> = GenericScore {
+        player: 1,
+        value: "value",
+    };
+    let with_player: WithPlayer<&str> = unresolved.with_player(PlayerData { player_id: 1 });
+    assert_eq!(with_player.value, "value");
+}
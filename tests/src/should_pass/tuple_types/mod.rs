@@ -0,0 +1,24 @@
+#[either_field::make_template(
+    WithId: [
+        0: i32
+    ],
+    WithName: [_, &'static str],
+    WithBoth: [i32, &'static str],
+)]
+#[derive(Debug, PartialEq)]
+struct Player<T>(
+    either_field::either!(() | i32),
+    either_field::either!(() | &'static str),
+    T,
+)
+where
+    T: Copy;
+
+fn main() {
+    let with_id: WithId<u8> = Player(1, (), 0);
+    let with_name: WithName<u8> = Player((), "name", 0);
+    let with_both: WithBoth<u8> = Player(with_id.0, with_name.1, with_id.2);
+    assert_eq!(with_both, Player(1, "name", 0));
+    let generic: Player<u32, u32, u32> = Player(1, 2, 3);
+    assert_eq!(generic.0 + generic.1, generic.2);
+}
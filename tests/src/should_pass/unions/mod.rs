@@ -0,0 +1,53 @@
+use std::mem::ManuallyDrop;
+
+#[either_field::make_template(
+    AsInt: [
+        value: i32
+    ],
+    AsFloat: [
+        value: f32
+    ]
+)]
+#[repr(C)]
+union Raw {
+    value: either_field::either!(u32 | i32 | f32),
+    bytes: [u8; 4],
+}
+
+#[either_field::make_template(
+    GenStructs: true,
+    DeleteTemplate: true;
+    Owned: [
+        value: ManuallyDrop<String>
+    ],
+    Borrowed: [
+        value: &'static str
+    ]
+)]
+#[repr(C)]
+union Text {
+    value: either_field::either!(u64 | ManuallyDrop<String> | &'static str),
+    address: usize,
+}
+
+#[either_field::template_impl(Raw)]
+impl Raw {
+    fn bytes(&self) -> [u8; 4] {
+        unsafe { self.bytes }
+    }
+}
+
+fn main() {
+    let int: AsInt = Raw { value: 1 };
+    let float: AsFloat = Raw { value: 1.0 };
+    assert_eq!(int.bytes(), 1i32.to_ne_bytes());
+    assert_eq!(float.bytes(), 1.0f32.to_ne_bytes());
+
+    let mut owned = Owned {
+        value: ManuallyDrop::new(String::from("owned")),
+    };
+    assert_eq!(unsafe { owned.value.as_str() }, "owned");
+    unsafe { ManuallyDrop::drop(&mut owned.value) };
+    let borrowed = Borrowed { value: "borrowed" };
+    assert_eq!(unsafe { borrowed.value }, "borrowed");
+}
@@ -0,0 +1,5 @@
+include!("../../../../example/src/usage1.rs");
+
+fn main() {
+    test();
+}
@@ -0,0 +1,5 @@
+include!("../../../../example/src/usage2.rs");
+
+fn main() {
+    test();
+}
@@ -0,0 +1,87 @@
+#[derive(Debug, PartialEq)]
+struct PlayerData {
+    player_id: i32,
+    player_name: String,
+}
+
+#[either_field::make_template(
+    ScoreWithPlayer: [
+        player: PlayerData
+    ],
+    ScoreWithNamedPlayer: [
+        player: PlayerData,
+        tag: String
+    ],
+    ScoreWithId: [
+        player: i32
+    ]
+)]
+struct Score<V> {
+    player: either_field::either!(i32 | PlayerData),
+    tag: either_field::either!(() | String),
+    value: V,
+}
+
+#[either_field::variant_impl(Score: [player: PlayerData])]
+impl<V> Score<V> {
+    fn player_name(&self) -> &str {
+        &self.player.player_name
+    }
+}
+
+#[either_field::variant_impl(Score: [player: _])]
+impl<V> Score<V> {
+    fn player_id(&self) -> either_field::either!(player) {
+        self.player
+    }
+}
+
+#[either_field::make_template(
+    GenStructs: true;
+    Ready: [
+        state: bool
+    ],
+    Pending: [
+        state: ()
+    ]
+)]
+struct Job {
+    state: either_field::either!(() | bool),
+}
+
+#[either_field::variant_impl(Job: [state: bool])]
+impl Job {
+    fn is_done(&self) -> bool {
+        self.state
+    }
+}
+
+fn main() {
+    let with_player: ScoreWithPlayer<u8> = Score {
+        player: PlayerData {
+            player_id: 1,
+            player_name: String::from("Player"),
+        },
+        tag: (),
+        value: 1,
+    };
+    let with_named_player: ScoreWithNamedPlayer<u8> = Score {
+        player: PlayerData {
+            player_id: 2,
+            player_name: String::from("Named"),
+        },
+        tag: String::from("tag"),
+        value: 2,
+    };
+    let with_id: ScoreWithId<u8> = Score {
+        player: 3,
+        tag: (),
+        value: 3,
+    };
+    assert_eq!(with_player.player_name(), "Player");
+    assert_eq!(with_named_player.player_name(), "Named");
+    assert_eq!(with_id.player_id(), 3);
+
+    let _ = Pending { state: () };
+    assert!(Ready { state: true }.is_done());
+}
@@ -0,0 +1,51 @@
+mod proto {
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Score<P, V> {
+        pub player: P,
+        pub value: V,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PlayerData {
+    name: String,
+}
+
+either_field::variants! {
+    type proto::Score<player, value = i32>
+    => ScoreWithId: [player: u64], ScoreWithPlayer: [player: PlayerData, value: _],
+    pub ScoreWithFloat: [player: u64, value: f64]
+}
+
+either_field::variants! {
+    #[derive(Debug)]
+    struct Entry {
+        key: either_field::either!(String | u32),
+        #[default(0)]
+        hits: u64,
+    }
+    => GenStructs: true;
+    NamedEntry: [key: String],
+    IndexedEntry: [key: u32]
+}
+
+fn main() {
+    let with_id: ScoreWithId = proto::Score { player: 1, value: 2 };
+    let with_player: ScoreWithPlayer = proto::Score {
+        player: PlayerData {
+            name: "p".to_string(),
+        },
+        value: with_id.value,
+    };
+    let with_float: ScoreWithFloat = proto::Score {
+        player: with_id.player,
+        value: 0.5,
+    };
+    assert_eq!(with_player.value, 2);
+    assert_eq!(with_float.player, 1);
+
+    let named = NamedEntry::new("a".to_string());
+    let indexed = IndexedEntry::new(3);
+    assert_eq!(named.hits + indexed.hits, 0);
+    assert_eq!(indexed.key, 3);
+}
@@ -0,0 +1,51 @@
+struct Validated;
+
+#[either_field::make_template(
+    GenStructs: true,
+    OmitEmptyTupleFields: true,
+    GenViews: true;
+    Unchecked: [
+        state: ()
+    ],
+    Checked: [
+        state: ::core::marker::PhantomData<Validated>
+    ],
+    Summed: [
+        state: u64
+    ]
+)]
+#[repr(C)]
+struct Samples {
+    state: either_field::either!(() | ::core::marker::PhantomData<Validated> | u64),
+    values: [u32; 1024],
+}
+
+#[either_field::make_template(
+    GenViews: true;
+    Plain: [
+        0: ()
+    ],
+    Tagged: [
+        0: ::core::marker::PhantomData<Validated>
+    ]
+)]
+#[repr(C)]
+struct Wrapper<T>(either_field::either!(() | ::core::marker::PhantomData<Validated>), T);
+
+fn main() {
+    let unchecked = Unchecked { values: [1; 1024] };
+    let checked: &Checked = unchecked.as_checked();
+    assert_eq!(checked.values.iter().sum::<u32>(), 1024);
+    let back: &Unchecked = checked.as_unchecked();
+    assert!(std::ptr::eq(back, &unchecked));
+
+    let plain: Plain<String> = Wrapper((), String::from("value"));
+    let tagged: &Tagged<String> = plain.as_tagged();
+    assert_eq!(tagged.1, "value");
+
+    let summed = Summed {
+        state: 0,
+        values: [0; 1024],
+    };
+    assert_eq!(summed.state, 0);
+}
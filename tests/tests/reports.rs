@@ -0,0 +1,34 @@
+// In its own test binary, as the reports are only written with an environment
+// variable set, which the other tests would race with
+
+fn report(dir: &std::path::Path, template: &str) -> String {
+    let prefix = format!("{template}-");
+    let entry = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap())
+        .find(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .unwrap_or_else(|| panic!("no report for {template}"));
+    std::fs::read_to_string(entry.path()).unwrap()
+}
+
+#[test]
+fn reports() {
+    let dir =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/either_field_reports");
+    // SAFETY: this is the only test of its binary, so no other thread
+    // reads or writes the environment meanwhile
+    unsafe { std::env::set_var("EITHER_FIELD_REPORT_DIR", &dir) };
+    let t = trybuild::TestCases::new();
+    t.pass("src/should_pass/report/mod.rs");
+    drop(t);
+
+    let types = report(&dir, "Reported");
+    assert!(types.contains("ReportedWithId = Reported<T, u64>"));
+    assert!(types.contains("ReportedWithName = Reported<T, String>"));
+    let structs = report(&dir, "ReportedEntry");
+    assert!(structs.contains("struct ReportedNamed"));
+    assert!(structs.contains("player: Vec<String>"));
+    assert!(structs.contains("omitted: player"));
+    let pairs = report(&dir, "ReportedPair");
+    assert!(pairs.contains("ReportedPairOfBytes = ReportedPair<u8>"));
+}